@@ -0,0 +1,564 @@
+//! Expression evaluation.
+//!
+//! Expressions are numbers and units combined with `+`, `-`, `*`, `/` and `^`,
+//! e.g. `3 kN * 2 m` or `sqrt(9.81 m/s^2 * 10 m)`.
+//! A number followed by a unit is a multiplication with a higher precedence than `/`,
+//! so `3 m / 2 s` is `(3 m) / (2 s)`.
+//! The result is in coherent SI units, unless the expression ends with `to <unit>`.
+//!
+//! A number directly followed by a temperature unit with an offset, e.g. `20 °F`,
+//! is an absolute temperature. In all other places, such units are temperature differences.
+
+use std::fmt;
+
+use datastruct::DataStruct;
+
+use ucsi::core::{
+    dyn_value::{DynError, DynValue},
+    format::{
+        ufmt::{SiFormatter, SiFormatterOption, SiLatex, SiLatexOption, SiUnicode, SiUnicodeOption},
+        MapFormat,
+    },
+    units::{
+        base::BaseUnitMap,
+        convert::{parse_unit, ConvertError, UnitExpr},
+        registry::{find_by_name, find_by_symbol, BUILTIN_UNITS},
+        scale::Scale,
+    },
+};
+
+/// Output style of results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    #[default]
+    Plain,
+    Unicode,
+    Latex,
+}
+
+/// Errors of expression evaluation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// Invalid expression, `position` is the byte offset in the input.
+    Syntax { position: usize, message: String },
+    /// A unit could not be parsed.
+    Unit { position: usize, error: ConvertError },
+    /// The operands of `op` have different dimensions.
+    DimensionMismatch {
+        op: &'static str,
+        lhs: Box<BaseUnitMap>,
+        rhs: Box<BaseUnitMap>,
+    },
+    /// The argument of a transcendental function is not dimensionless.
+    NotDimensionless {
+        function: String,
+        unit: Box<BaseUnitMap>,
+    },
+}
+
+impl EvalError {
+    /// Byte offset of the error in the input, if any.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            EvalError::Syntax { position, .. } | EvalError::Unit { position, .. } => {
+                Some(*position)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn plain_unit(map: &BaseUnitMap) -> String {
+    if map.eq(&BaseUnitMap::EMPTY) {
+        return "1".to_string();
+    }
+    render_unit(Style::Plain, map)
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Syntax { position, message } => {
+                write!(f, "syntax error at {}: {}", position, message)
+            }
+            EvalError::Unit { position, error } => write!(f, "at {}: {}", position, error),
+            EvalError::DimensionMismatch { op, lhs, rhs } => {
+                write!(
+                    f,
+                    "dimension mismatch in `{}`: `{}` and `{}` (",
+                    op,
+                    plain_unit(lhs),
+                    plain_unit(rhs)
+                )?;
+                let mut first = true;
+                for ((name, l), (_, r)) in lhs.all_fields().zip(rhs.all_fields()) {
+                    if l.simplify().eq(&r.simplify()) {
+                        continue;
+                    }
+                    if !first {
+                        write!(f, ", ")?;
+                    }
+                    first = false;
+                    write!(f, "{}: {} vs {}", name, l.simplify(), r.simplify())?;
+                }
+                write!(f, ")")
+            }
+            EvalError::NotDimensionless { function, unit } => {
+                write!(
+                    f,
+                    "`{}` expects a dimensionless argument, found `{}`",
+                    function,
+                    plain_unit(unit)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The result of an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    /// The value in coherent SI units.
+    pub value: DynValue<f64>,
+    /// The unit after `to`, with its source text.
+    pub target: Option<(String, UnitExpr)>,
+}
+
+impl Evaluation {
+    /// The value in the target unit, or in coherent SI units.
+    pub fn magnitude(&self) -> f64 {
+        match &self.target {
+            Some((_, unit)) => unit.from_coherent(self.value.value),
+            None => self.value.value,
+        }
+    }
+
+    pub fn render(&self, style: Style) -> String {
+        let number = format_number(self.magnitude());
+        let unit = match &self.target {
+            Some((text, _)) if style == Style::Latex => format!("\\mathrm{{{}}}", text),
+            Some((text, _)) => text.clone(),
+            None if self.value.unit().eq(&BaseUnitMap::EMPTY) => return number,
+            None => render_unit(style, self.value.unit()),
+        };
+        match style {
+            Style::Latex => format!("{} \\, {}", number, unit),
+            _ => format!("{} {}", number, unit),
+        }
+    }
+}
+
+fn render_unit(style: Style, map: &BaseUnitMap) -> String {
+    let mut string = String::new();
+    match style {
+        Style::Plain => SiFormatter::mfmt(
+            SiFormatterOption::data_default().with_unit_seperator("*"),
+            Scale::ONE,
+            map,
+            &mut string,
+        ),
+        Style::Unicode => SiUnicode::mfmt(SiUnicodeOption::data_default(), Scale::ONE, map, &mut string),
+        Style::Latex => SiLatex::mfmt(
+            SiLatexOption::data_default().with_unit_seperator(" \\cdot "),
+            Scale::ONE,
+            map,
+            &mut string,
+        ),
+    }
+    .unwrap();
+    string
+}
+
+/// Format with 12 significant digits, using scientific notation for very large or small values.
+pub fn format_number(value: f64) -> String {
+    let rounded: f64 = format!("{:.11e}", value).parse().unwrap_or(value);
+    let abs = rounded.abs();
+    if abs != 0.0 && !(1e-6..1e15).contains(&abs) {
+        format!("{:e}", rounded)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    To,
+    End,
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '°' | '′' | '″' | '☉' | '⊕')
+}
+
+fn tokenize(src: &str) -> Result<Vec<(usize, Token)>, EvalError> {
+    let mut tokens = Vec::new();
+    let mut chars = src.char_indices().peekable();
+
+    while let Some(&(pos, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = pos;
+            let mut last = ' ';
+            while let Some(&(i, c)) = chars.peek() {
+                let exponent = matches!(c, 'e' | 'E')
+                    && src[i + 1..].starts_with(|n: char| n.is_ascii_digit() || n == '-' || n == '+');
+                let sign = matches!(c, '-' | '+') && matches!(last, 'e' | 'E');
+                if !(c.is_ascii_digit() || c == '.' || exponent || sign) {
+                    break;
+                }
+                last = c;
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            let number = src[pos..end].parse().map_err(|_| EvalError::Syntax {
+                position: pos,
+                message: format!("invalid number `{}`", &src[pos..end]),
+            })?;
+            tokens.push((pos, Token::Number(number)));
+        } else if is_ident_char(c) {
+            let mut end = pos;
+            while let Some(&(i, c)) = chars.peek() {
+                if !is_ident_char(c) {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            let ident = &src[pos..end];
+            if ident == "to" {
+                tokens.push((pos, Token::To));
+            } else {
+                tokens.push((pos, Token::Ident(ident.to_string())));
+            }
+        } else {
+            chars.next();
+            let op = match c {
+                '*' if chars.peek().map(|&(_, c)| c) == Some('*') => {
+                    chars.next();
+                    '^'
+                }
+                '-' if chars.peek().map(|&(_, c)| c) == Some('>') => {
+                    chars.next();
+                    tokens.push((pos, Token::To));
+                    continue;
+                }
+                '·' | '×' => '*',
+                '−' => '-',
+                '+' | '-' | '*' | '/' | '^' | '(' | ')' => c,
+                _ => {
+                    return Err(EvalError::Syntax {
+                        position: pos,
+                        message: format!("unexpected character `{}`", c),
+                    })
+                }
+            };
+            tokens.push((pos, Token::Op(op)));
+        }
+    }
+
+    tokens.push((src.len(), Token::End));
+    Ok(tokens)
+}
+
+const FUNCTIONS: &[&str] = &["sqrt", "cbrt", "abs", "exp", "ln", "log10", "sin", "cos", "tan"];
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].1
+    }
+
+    fn peek_at(&self, offset: usize) -> &Token {
+        &self.tokens[(self.pos + offset).min(self.tokens.len() - 1)].1
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.pos].0
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].1.clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        if self.peek() == &Token::Op(op) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, EvalError> {
+        Err(EvalError::Syntax {
+            position: self.position(),
+            message: message.into(),
+        })
+    }
+
+    fn expect(&mut self, op: char) -> Result<(), EvalError> {
+        if self.eat(op) {
+            Ok(())
+        } else {
+            self.error(format!("expected `{}`", op))
+        }
+    }
+
+    fn evaluation(&mut self) -> Result<Evaluation, EvalError> {
+        let value = self.sum()?;
+        let target = match self.peek() {
+            Token::End => None,
+            Token::To => {
+                self.next();
+                let position = self.position();
+                let text = self.src[position..].trim();
+                if text.is_empty() {
+                    return self.error("expected a unit after `to`");
+                }
+                let unit = parse_unit(BUILTIN_UNITS, text)
+                    .map_err(|error| EvalError::Unit { position, error })?;
+                if !unit.unit_map.eq(value.unit()) {
+                    return Err(EvalError::DimensionMismatch {
+                        op: "to",
+                        lhs: Box::new(*value.unit()),
+                        rhs: Box::new(unit.unit_map),
+                    });
+                }
+                Some((text.to_string(), unit))
+            }
+            _ => return self.error("unexpected token"),
+        };
+        Ok(Evaluation { value, target })
+    }
+
+    fn sum(&mut self) -> Result<DynValue<f64>, EvalError> {
+        let mut lhs = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Token::Op('+') => "+",
+                Token::Op('-') => "-",
+                _ => return Ok(lhs),
+            };
+            self.next();
+            let rhs = self.product()?;
+            if !lhs.is_same_unit(&rhs) {
+                return Err(EvalError::DimensionMismatch {
+                    op,
+                    lhs: Box::new(*lhs.unit()),
+                    rhs: Box::new(*rhs.unit()),
+                });
+            }
+            let value = if op == "+" {
+                lhs.value + rhs.value
+            } else {
+                lhs.value - rhs.value
+            };
+            lhs = DynValue::new(value, *lhs.unit());
+        }
+    }
+
+    fn product(&mut self) -> Result<DynValue<f64>, EvalError> {
+        let mut lhs = self.implicit_product()?;
+        loop {
+            let position = self.position();
+            let result = if self.eat('*') {
+                lhs * self.implicit_product()?
+            } else if self.eat('/') {
+                lhs / self.implicit_product()?
+            } else {
+                return Ok(lhs);
+            };
+            lhs = result.map_err(arithmetic_error(position))?;
+        }
+    }
+
+    fn implicit_product(&mut self) -> Result<DynValue<f64>, EvalError> {
+        let mut lhs = self.unary()?;
+        while matches!(self.peek(), Token::Number(_) | Token::Ident(_) | Token::Op('(')) {
+            let position = self.position();
+            lhs = (lhs * self.power()?).map_err(arithmetic_error(position))?;
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<DynValue<f64>, EvalError> {
+        if self.eat('-') {
+            Ok(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Result<DynValue<f64>, EvalError> {
+        let base = self.atom()?;
+        if !self.eat('^') {
+            return Ok(base);
+        }
+        let position = self.position();
+        let (num, den) = self.exponent()?;
+        let result = if den == 1 {
+            base.to_coherent().powi(num)
+        } else {
+            base.powf(num, den)
+        };
+        result.map_err(arithmetic_error(position))
+    }
+
+    fn integer(&mut self) -> Result<i32, EvalError> {
+        let negative = self.eat('-');
+        match *self.peek() {
+            Token::Number(n) if n.fract() == 0.0 && n.abs() <= i32::MAX as f64 => {
+                self.next();
+                Ok(if negative { -(n as i32) } else { n as i32 })
+            }
+            _ => self.error("expected an integer exponent"),
+        }
+    }
+
+    fn exponent(&mut self) -> Result<(i32, u32), EvalError> {
+        if !self.eat('(') {
+            return Ok((self.integer()?, 1));
+        }
+        let num = self.integer()?;
+        let den = if self.eat('/') { self.integer()? } else { 1 };
+        self.expect(')')?;
+        if den <= 0 {
+            return self.error("expected a positive denominator");
+        }
+        Ok((num, den as u32))
+    }
+
+    fn atom(&mut self) -> Result<DynValue<f64>, EvalError> {
+        let position = self.position();
+        match self.peek().clone() {
+            Token::Number(n) => {
+                self.next();
+                // an absolute temperature, e.g. `20 °F`
+                if let Token::Ident(ident) = self.peek().clone() {
+                    if self.peek_at(1) != &Token::Op('^') {
+                        if let Ok(unit) = parse_unit(BUILTIN_UNITS, &ident) {
+                            if unit.offset != 0.0 {
+                                self.next();
+                                return Ok(unit.to_dyn(n));
+                            }
+                        }
+                    }
+                }
+                Ok(DynValue::dimensionless(n))
+            }
+            Token::Op('(') => {
+                self.next();
+                let inner = self.sum()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Token::Ident(ident) => {
+                self.next();
+                if FUNCTIONS.contains(&ident.as_str()) && self.eat('(') {
+                    let arg = self.sum()?;
+                    self.expect(')')?;
+                    self.function(position, ident, arg)
+                } else {
+                    self.unit(position, ident)
+                }
+            }
+            _ => self.error("expected a number or a unit"),
+        }
+    }
+
+    /// Resolve a unit, joining following words for symbols like `imp gal`.
+    fn unit(&mut self, position: usize, ident: String) -> Result<DynValue<f64>, EvalError> {
+        let mut words = ident.clone();
+        let mut joined = None;
+        let mut offset = 0;
+        while let Token::Ident(next) = self.peek_at(offset) {
+            words = format!("{} {}", words, next);
+            offset += 1;
+            if find_by_symbol(BUILTIN_UNITS, &words)
+                .or_else(|| find_by_name(BUILTIN_UNITS, &words))
+                .is_some()
+            {
+                joined = Some((words.clone(), offset));
+            }
+        }
+        let text = match joined {
+            Some((words, offset)) => {
+                self.pos += offset;
+                words
+            }
+            None => ident,
+        };
+
+        let unit = parse_unit(BUILTIN_UNITS, &text)
+            .map_err(|error| EvalError::Unit { position, error })?;
+        Ok(DynValue::new(unit.factor, unit.unit_map))
+    }
+
+    fn function(
+        &self,
+        position: usize,
+        name: String,
+        arg: DynValue<f64>,
+    ) -> Result<DynValue<f64>, EvalError> {
+        let arg = arg.to_coherent();
+        let root = match name.as_str() {
+            "sqrt" => Some(2),
+            "cbrt" => Some(3),
+            "abs" => return Ok(arg.map(f64::abs)),
+            _ => None,
+        };
+        if let Some(den) = root {
+            return arg.powf(1, den).map_err(arithmetic_error(position));
+        }
+        if !arg.unit().eq(&BaseUnitMap::EMPTY) {
+            return Err(EvalError::NotDimensionless {
+                function: name,
+                unit: Box::new(*arg.unit()),
+            });
+        }
+        let f: fn(f64) -> f64 = match name.as_str() {
+            "exp" => f64::exp,
+            "ln" => f64::ln,
+            "log10" => f64::log10,
+            "sin" => f64::sin,
+            "cos" => f64::cos,
+            _ => f64::tan,
+        };
+        Ok(arg.map(f))
+    }
+}
+
+/// Report a [`DynError`] of the operation at `position` as a syntax error.
+fn arithmetic_error(position: usize) -> impl FnOnce(DynError) -> EvalError {
+    move |error| EvalError::Syntax {
+        position,
+        message: error.to_string(),
+    }
+}
+
+/// Evaluate an expression.
+pub fn evaluate(src: &str) -> Result<Evaluation, EvalError> {
+    let mut parser = Parser {
+        src,
+        tokens: tokenize(src)?,
+        pos: 0,
+    };
+    parser.evaluation()
+}
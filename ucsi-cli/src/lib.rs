@@ -0,0 +1,21 @@
+//! # The `ucsi` calculator
+//!
+//! A dimension-checked calculator built on the runtime APIs of the `ucsi` library:
+//! units are parsed by [`ucsi::core::units::convert`],
+//! values are [`DynValue`](ucsi::core::dyn_value::DynValue)s,
+//! and results are written by the [formatters](ucsi::core::format::ufmt).
+//!
+//! ```rust
+//! use ucsi_cli::eval::{evaluate, Style};
+//!
+//! let work = evaluate("3 kN * 2 m to J").unwrap();
+//! assert_eq!(work.render(Style::Plain), "6000 J");
+//!
+//! let speed = evaluate("sqrt(9.81 m/s^2 * 10 m)").unwrap();
+//! assert_eq!(speed.render(Style::Unicode), "9.90454441153 m·s⁻¹");
+//!
+//! assert!(evaluate("1 m + 1 s").is_err());
+//! ```
+
+/// Expression evaluation.
+pub mod eval;
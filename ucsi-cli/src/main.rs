@@ -0,0 +1,85 @@
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use ucsi_cli::eval::{evaluate, EvalError, Style};
+
+const HELP: &str = "\
+Usage: ucsi [--latex | --unicode] [EXPRESSION...]
+
+Evaluate a dimension-checked expression, e.g. `3 kN * 2 m to J`.
+Without an expression, start an interactive session.
+
+Options:
+  --latex      Write results as LaTeX
+  --unicode    Write units with unicode superscripts
+  -h, --help   Print this help
+
+Commands in the interactive session:
+  :plain, :unicode, :latex   Switch the output style
+  :quit                      Exit";
+
+fn report(input: &str, error: &EvalError) {
+    eprintln!("error: {}", error);
+    if let Some(position) = error.position() {
+        eprintln!("  {}", input);
+        eprintln!("  {}^", " ".repeat(input[..position].chars().count()));
+    }
+}
+
+fn repl(mut style: Style) -> ExitCode {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return ExitCode::SUCCESS;
+        };
+
+        match line.trim() {
+            "" => {}
+            ":quit" | ":q" | "exit" | "quit" => return ExitCode::SUCCESS,
+            ":plain" => style = Style::Plain,
+            ":unicode" => style = Style::Unicode,
+            ":latex" => style = Style::Latex,
+            ":help" => println!("{}", HELP),
+            input => match evaluate(input) {
+                Ok(result) => println!("{}", result.render(style)),
+                Err(error) => report(input, &error),
+            },
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut style = Style::Plain;
+    let mut expression = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--latex" => style = Style::Latex,
+            "--unicode" => style = Style::Unicode,
+            "-h" | "--help" => {
+                println!("{}", HELP);
+                return ExitCode::SUCCESS;
+            }
+            _ => expression.push(arg),
+        }
+    }
+
+    if expression.is_empty() {
+        return repl(style);
+    }
+
+    let input = expression.join(" ");
+    match evaluate(&input) {
+        Ok(result) => {
+            println!("{}", result.render(style));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            report(&input, &error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! Lexer and parser of unit expressions.
+
+use proc_macro::{Delimiter, Span, TokenStream, TokenTree};
+
+pub(crate) struct Error {
+    pub message: String,
+    pub span: Span,
+}
+
+fn error<T>(message: impl Into<String>, span: Span) -> Result<T, Error> {
+    Err(Error {
+        message: message.into(),
+        span,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Token {
+    /// A path segment, or `::`.
+    Ident(String, Span),
+    Int(i64, Span),
+    /// One of `* / ^ - ( ) ::`, `**` is lexed as `^`.
+    Op(&'static str, Span),
+    /// Generic arguments of the previous path, e.g. `<m, 1000, 1>`.
+    Generic(String, Span),
+    End(Span),
+}
+
+fn op(c: char) -> Option<&'static str> {
+    Some(match c {
+        '*' | '·' | '⋅' | '×' => "*",
+        '/' => "/",
+        '^' => "^",
+        '-' | '−' => "-",
+        '(' => "(",
+        ')' => ")",
+        _ => return None,
+    })
+}
+
+/// Lex the tokens of a macro invocation.
+pub(crate) fn lex_tokens(input: TokenStream) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    lex_stream(input, &mut tokens)?;
+    tokens.push(Token::End(Span::call_site()));
+    Ok(tokens)
+}
+
+fn lex_stream(input: TokenStream, tokens: &mut Vec<Token>) -> Result<(), Error> {
+    let mut iter = input.into_iter().peekable();
+    while let Some(tree) = iter.next() {
+        match tree {
+            TokenTree::Ident(ident) => tokens.push(Token::Ident(ident.to_string(), ident.span())),
+            TokenTree::Literal(lit) => match lit.to_string().replace('_', "").parse() {
+                Ok(n) => tokens.push(Token::Int(n, lit.span())),
+                Err(_) => return error("expected an integer", lit.span()),
+            },
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    // `{ 2 }` in the legacy power syntax
+                    Delimiter::Parenthesis | Delimiter::Brace => ("(", ")"),
+                    Delimiter::None => {
+                        lex_stream(group.stream(), tokens)?;
+                        continue;
+                    }
+                    Delimiter::Bracket => return error("unexpected `[`", group.span()),
+                };
+                tokens.push(Token::Op(open, group.span_open()));
+                lex_stream(group.stream(), tokens)?;
+                tokens.push(Token::Op(close, group.span_close()));
+            }
+            TokenTree::Punct(punct) => {
+                let span = punct.span();
+                match punct.as_char() {
+                    '*' if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '*') => {
+                        iter.next();
+                        tokens.push(Token::Op("^", span));
+                    }
+                    ':' if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ':') => {
+                        iter.next();
+                        tokens.push(Token::Op("::", span));
+                    }
+                    '<' => {
+                        let mut depth = 1;
+                        let mut args = Vec::new();
+                        for tree in iter.by_ref() {
+                            match &tree {
+                                TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+                                TokenTree::Punct(p) if p.as_char() == '>' => {
+                                    depth -= 1;
+                                    if depth == 0 {
+                                        break;
+                                    }
+                                }
+                                _ => {}
+                            }
+                            args.push(tree);
+                        }
+                        if depth != 0 {
+                            return error("unclosed `<`", span);
+                        }
+                        // keep the spacing of joint punctuation, e.g. `::`
+                        let args: TokenStream = args.into_iter().collect();
+                        tokens.push(Token::Generic(format!("<{}>", args), span));
+                    }
+                    c => match op(c) {
+                        Some(op) => tokens.push(Token::Op(op, span)),
+                        None => return error(format!("unexpected `{}`", c), span),
+                    },
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn superscript(c: char) -> Option<char> {
+    Some(match c {
+        '⁰' => '0',
+        '¹' => '1',
+        '²' => '2',
+        '³' => '3',
+        '⁴' => '4',
+        '⁵' => '5',
+        '⁶' => '6',
+        '⁷' => '7',
+        '⁸' => '8',
+        '⁹' => '9',
+        '⁻' => '-',
+        'ᐟ' => '/',
+        _ => return None,
+    })
+}
+
+/// Lex a string literal, e.g. `"kg·m/s²"`. Superscripts become `^(...)`.
+pub(crate) fn lex_str(src: &str, span: Span) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = src.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit() || **d == '_') {
+                digits.push(d);
+                chars.next();
+            }
+            match digits.replace('_', "").parse() {
+                Ok(n) => tokens.push(Token::Int(n, span)),
+                Err(_) => return error(format!("integer `{}` is too large", digits), span),
+            }
+        } else if superscript(c).is_none() && (c.is_alphabetic() || c == '_') {
+            let is_ident = |d: &&char| superscript(**d).is_none() && (d.is_alphanumeric() || **d == '_');
+            let mut ident = String::new();
+            while let Some(&d) = chars.peek().filter(is_ident) {
+                ident.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Ident(ident, span));
+        } else if superscript(c).is_some() {
+            let mut exponent = String::new();
+            while let Some(d) = chars.peek().and_then(|&d| superscript(d)) {
+                exponent.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Op("^", span));
+            tokens.push(Token::Op("(", span));
+            for part in exponent.split_inclusive(['-', '/']) {
+                let (digits, sep) = match part.strip_suffix(['-', '/']) {
+                    Some(digits) => (digits, Some(&part[digits.len()..])),
+                    None => (part, None),
+                };
+                if !digits.is_empty() {
+                    match digits.parse() {
+                        Ok(n) => tokens.push(Token::Int(n, span)),
+                        Err(_) => return error("invalid superscript exponent", span),
+                    }
+                }
+                if let Some(sep) = sep {
+                    tokens.push(Token::Op(if sep == "-" { "-" } else { "/" }, span));
+                }
+            }
+            tokens.push(Token::Op(")", span));
+        } else if c == ':' {
+            chars.next();
+            if chars.next() != Some(':') {
+                return error("unexpected `:`", span);
+            }
+            tokens.push(Token::Op("::", span));
+        } else {
+            chars.next();
+            match op(c) {
+                Some(op) => tokens.push(Token::Op(op, span)),
+                None => return error(format!("unexpected `{}`", c), span),
+            }
+        }
+    }
+    tokens.push(Token::End(span));
+    Ok(tokens)
+}
+
+const OPS: &str = "::ucsi::core::ops";
+
+pub(crate) struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Parser {
+        Parser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        if matches!(self.peek(), Token::Op(op, _) if *op == expected) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), Error> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            error(format!("expected `{}`", expected), self.peek().span())
+        }
+    }
+
+    /// Parse the whole input into a rust type.
+    pub fn parse(mut self) -> Result<String, Error> {
+        if let Token::End(span) = self.peek() {
+            return error("expected a unit expression", *span);
+        }
+        let ty = self.product()?;
+        match self.peek() {
+            Token::End(_) => Ok(ty),
+            token => error("expected `*`, `/` or the end of the expression", token.span()),
+        }
+    }
+
+    fn product(&mut self) -> Result<String, Error> {
+        let mut lhs = self.power()?;
+        loop {
+            if self.eat("*") {
+                lhs = format!("{}::Mul<{}, {}>", OPS, lhs, self.power()?);
+            } else if self.eat("/") {
+                lhs = format!("{}::Div<{}, {}>", OPS, lhs, self.power()?);
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn power(&mut self) -> Result<String, Error> {
+        let base = self.primary()?;
+        if !self.eat("^") {
+            return Ok(base);
+        }
+        let (num, den) = if self.eat("(") {
+            let num = self.integer()?;
+            let den = if self.eat("/") {
+                let span = self.peek().span();
+                match self.integer()? {
+                    den if den > 0 => Some(den),
+                    _ => return error("the denominator must be positive", span),
+                }
+            } else {
+                None
+            };
+            self.expect(")")?;
+            (num, den)
+        } else {
+            (self.integer()?, None)
+        };
+        if i32::try_from(num).is_err() || den.is_some_and(|d| u32::try_from(d).is_err()) {
+            return error("the exponent is out of range", self.tokens[self.pos - 1].span());
+        }
+
+        match den {
+            None | Some(1) => Ok(format!("{}::PowI<{}, {}>", OPS, base, num)),
+            Some(den) => Ok(format!("{}::PowFrac<{}, {}, {}>", OPS, base, num, den)),
+        }
+    }
+
+    fn integer(&mut self) -> Result<i64, Error> {
+        let negative = self.eat("-");
+        match self.next() {
+            Token::Int(n, _) => Ok(if negative { -n } else { n }),
+            token => error("expected an integer exponent", token.span()),
+        }
+    }
+
+    fn primary(&mut self) -> Result<String, Error> {
+        match self.next() {
+            Token::Op("(", _) => {
+                let inner = self.product()?;
+                self.expect(")")?;
+                Ok(inner)
+            }
+            Token::Int(1, _) => Ok("::ucsi::core::units::base::PureValue".to_string()),
+            Token::Int(_, span) => error("only `1` is allowed as a number, use `Scaled` for factors", span),
+            Token::Op("::", _) => self.path("::".to_string()),
+            Token::Ident(ident, _) => self.path(ident),
+            token => error("expected a unit", token.span()),
+        }
+    }
+
+    fn path(&mut self, mut path: String) -> Result<String, Error> {
+        if path == "::" {
+            match self.next() {
+                Token::Ident(ident, _) => path.push_str(&ident),
+                token => return error("expected an identifier", token.span()),
+            }
+        }
+        while self.eat("::") {
+            match self.next() {
+                Token::Ident(ident, _) => {
+                    path.push_str("::");
+                    path.push_str(&ident);
+                }
+                token => return error("expected an identifier", token.span()),
+            }
+        }
+        if let Token::Generic(args, _) = self.peek() {
+            path.push_str(args);
+            self.next();
+        }
+        Ok(path)
+    }
+}
@@ -0,0 +1,162 @@
+//! Procedural macros of the `ucsi` library.
+//!
+//! Use them through the `ucsi` crate with the `proc_macros` feature,
+//! e.g. [`ucsi::ops::unit`](https://docs.rs/ucsi/latest/ucsi/ops/macro.unit.html).
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+mod expr;
+
+use expr::{Error, Token};
+
+/// Declare a unit type with natural operator precedence.
+///
+/// `*` and `/` are left associative, `^` binds tighter and takes an integer
+/// or a parenthesized fraction. `1` is the dimensionless unit.
+/// The input may also be a plain or raw string literal with `·` for multiplication
+/// and unicode superscripts for exponents.
+///
+/// The macro expands to the same types as `ucsi::unit!`:
+///
+/// | input | type |
+/// | --- | --- |
+/// | `kg * m / s^2` | `Div<Mul<kg, m>, PowI<s, 2>>` |
+/// | `m^-1` | `PowI<m, -1>` |
+/// | `s^(1/2)` | `PowFrac<s, 1, 2>` |
+/// | `1/s` | `Div<PureValue, s>` |
+/// | `"kg·m/s²"` | `Div<Mul<kg, m>, PowI<s, 2>>` |
+///
+/// The legacy power syntax `s ** { 2 }` is accepted as well.
+/// The generated paths start with `::ucsi`.
+#[proc_macro]
+pub fn unit(input: TokenStream) -> TokenStream {
+    let tokens = match string_input(&input) {
+        Some(Ok((text, span))) => expr::lex_str(&text, span),
+        Some(Err(error)) => Err(error),
+        None => expr::lex_tokens(input),
+    };
+    let result = tokens.and_then(|tokens| expr::Parser::new(tokens).parse());
+    match result {
+        Ok(ty) => ty.parse().expect("generated type is valid rust"),
+        Err(error) => compile_error(error),
+    }
+}
+
+/// The content of a single string literal, `None` if the input is not a single string-like literal.
+///
+/// Plain and raw strings are supported, byte and C strings are rejected.
+fn string_input(input: &TokenStream) -> Option<Result<(String, Span), Error>> {
+    let mut iter = input.clone().into_iter();
+    let (Some(TokenTree::Literal(lit)), None) = (iter.next(), iter.next()) else {
+        return None;
+    };
+    let text = lit.to_string();
+    let span = lit.span();
+    let unsupported = || {
+        Some(Err(Error {
+            message: "only plain and raw string literals are supported".to_string(),
+            span,
+        }))
+    };
+
+    if let Some(raw) = text.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let delimiter = "#".repeat(hashes);
+        return match raw[hashes..]
+            .strip_prefix('"')
+            .and_then(|raw| raw.strip_suffix(&delimiter))
+            .and_then(|raw| raw.strip_suffix('"'))
+        {
+            Some(inner) => Some(Ok((inner.to_string(), span))),
+            None => unsupported(),
+        };
+    }
+    if text.starts_with(['b', 'c']) && text.contains(['"', '\'']) {
+        return unsupported();
+    }
+    if !text.starts_with('"') {
+        return None;
+    }
+    match text
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .and_then(unescape)
+    {
+        Some(inner) => Some(Ok((inner, span))),
+        None => unsupported(),
+    }
+}
+
+/// Resolve the escape sequences of a string literal, `None` if an escape is invalid.
+fn unescape(src: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = src.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            '0' => out.push('\0'),
+            '\\' => out.push('\\'),
+            '\'' => out.push('\''),
+            '"' => out.push('"'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) if hex.len() == 2 && byte <= 0x7f => out.push(byte as char),
+                    _ => return None,
+                }
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let mut hex = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        '_' => {}
+                        d => hex.push(d),
+                    }
+                }
+                out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            // line continuation, skips the newline and the leading whitespace
+            '\n' => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            }
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+fn compile_error(Error { message, span }: Error) -> TokenStream {
+    let mut message = TokenTree::Literal(Literal::string(&message));
+    message.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::from(message))),
+    ]
+    .into_iter()
+    .collect()
+}
+
+impl Token {
+    pub(crate) fn span(&self) -> Span {
+        match self {
+            Token::Ident(_, span)
+            | Token::Int(_, span)
+            | Token::Op(_, span)
+            | Token::Generic(_, span)
+            | Token::End(span) => *span,
+        }
+    }
+}
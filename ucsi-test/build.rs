@@ -0,0 +1,8 @@
+use std::{env, path::PathBuf};
+
+use ucsi::catalogue::Catalogue;
+
+fn main() {
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("catalogue.rs");
+    Catalogue::new("catalogue").generate(out);
+}
@@ -0,0 +1,53 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use ucsi::{
+    core::{
+        units::{
+            any::is_same_type,
+            associated::angle::{Arcminute, Arcsecond, Degree, Turn},
+            base::{m, PureValue, Radian},
+        },
+        value::Value,
+    },
+    val,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs - rhs).abs() < 1e-12
+}
+
+#[test]
+fn test_angle_dimension() {
+    assert!(!is_same_type::<Radian, PureValue>());
+    assert!(is_same_type::<Radian, Radian>());
+}
+
+#[test]
+fn test_angle_conversion() {
+    assert!(approx(val!(180.0 * Degree).to_radians().value, PI));
+    assert!(approx(val!(60.0 * Arcminute).to_radians().value, PI / 180.0));
+    assert!(approx(val!(3600.0 * Arcsecond).to_radians().value, PI / 180.0));
+    assert!(approx(val!(0.5 * Turn).to_radians().value, PI));
+    assert!(approx(val!(PI * Radian).to_radians().value, PI));
+
+    assert!(approx(val!(90.0_f64 * Degree).to_metric().value, FRAC_PI_2));
+    let deg = Value::<f64, Degree>::from_metric(val!(PI * Radian));
+    assert!(approx(deg.value, 180.0));
+    let turn = Value::<f32, Turn>::from_metric(Value::new(std::f32::consts::PI));
+    assert!((turn.value - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_trigonometry() {
+    assert!(approx(val!(90.0 * Degree).sin().value, 1.0));
+    assert!(approx(val!(0.5 * Turn).cos().value, -1.0));
+    assert!(approx(val!(FRAC_PI_4 * Radian).tan().value, 1.0));
+
+    let ratio: Value<f64, PureValue> = Value::new(1.0);
+    assert!(approx(ratio.asin().value, FRAC_PI_2));
+    assert!(approx(ratio.acos().value, 0.0));
+    assert!(approx(ratio.atan().value, FRAC_PI_4));
+
+    let angle: Value<f64, Radian> = val!(1.0 * m).atan2(val!(1.0 * m));
+    assert!(approx(angle.value, FRAC_PI_4));
+}
@@ -0,0 +1,91 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::{
+                area::Hectare,
+                energy::ElectronVolt,
+                length::AstronomicalUnit,
+                logarithmic::{Bel, Decibel, Neper},
+                time::{Day, Hour, Minute},
+                volume::Litre,
+                weight::{Dalton, Tonne},
+            },
+            base::{kg, m, s, PureValue},
+            exported::energy::Joule,
+        },
+        value::Value,
+    },
+    unit, SoftF64,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < 1e-12
+}
+
+#[test]
+fn test_time_units() {
+    let minute: Value<i32, s> = Value::<i32, Minute>::new(2).to_metric();
+    assert_eq!(minute.value, 120);
+    let hour: Value<u64, s> = Value::<u64, Hour>::new(1).to_metric();
+    assert_eq!(hour.value, 3600);
+    assert_eq!(Value::<i64, Day>::from_metric(Value::new(172_800)).value, 2);
+    assert!(approx(Value::<f64, Day>::new(0.5).to_metric().value, 43_200.0));
+
+    const HOUR: Value<SoftF64, s> = Value::<SoftF64, Hour>::new(SoftF64(1.5)).to_metric();
+    assert_eq!(HOUR.value.to_f64(), 5400.0);
+}
+
+#[test]
+fn test_narrow_int_units() {
+    let hour: Value<u16, s> = Value::<u16, Hour>::new(18).to_metric();
+    assert_eq!(hour.value, 64_800);
+    const TONNE: Value<i16, kg> = Value::<i16, Tonne>::new(-32).to_metric();
+    assert_eq!(TONNE.value, -32_000);
+    assert_eq!(Value::<u16, Litre>::from_metric(Value::new(65)).value, 65_000);
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_narrow_int_overflow() {
+    // `19 h = 68 400 s` does not fit into `u16`
+    let _: Value<u16, s> = Value::<u16, Hour>::new(19).to_metric();
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_narrow_int_overflow_from_metric() {
+    // `33 m³ = 33 000 L` does not fit into `i16`
+    let _ = Value::<i16, Litre>::from_metric(Value::new(33));
+}
+
+#[test]
+fn test_space_units() {
+    let area: Value<u32, unit!(m ** { 2 })> = Value::<u32, Hectare>::new(3).to_metric();
+    assert_eq!(area.value, 30_000);
+    let volume: Value<f64, unit!(m ** { 3 })> = Value::<f64, Litre>::new(1500.0).to_metric();
+    assert!(approx(volume.value, 1.5));
+    assert_eq!(Value::<i32, Litre>::from_metric(Value::new(2)).value, 2000);
+
+    let au: Value<u64, m> = Value::<u64, AstronomicalUnit>::new(1).to_metric();
+    assert_eq!(au.value, 149_597_870_700);
+}
+
+#[test]
+fn test_mass_energy_units() {
+    let mass: Value<u32, kg> = Value::<u32, Tonne>::new(2).to_metric();
+    assert_eq!(mass.value, 2000);
+    let mass: Value<f64, kg> = Value::<f64, Dalton>::new(12.0).to_metric();
+    assert!(approx(mass.value, 1.992_646_879_92e-26));
+
+    let energy: Value<f64, Joule> = Value::<f64, ElectronVolt>::new(1.0).to_metric();
+    assert!(approx(energy.value, 1.602_176_634e-19));
+}
+
+#[test]
+fn test_logarithmic_units() {
+    let bel: Value<f64, PureValue> = Value::<f64, Bel>::new(1.0).to_metric();
+    let neper: Value<f64, PureValue> = Value::<f64, Neper>::new(1.0).to_metric();
+    assert!(approx(bel.value, 10f64.ln() / 2.0));
+    assert_eq!(neper.value, 1.0);
+    assert!(approx(Value::<f64, Decibel>::from_metric(bel).value, 10.0));
+}
@@ -0,0 +1,58 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::astronomy::{
+                Arcsecond, AstronomicalUnit, EarthMass, JulianYear, LightYear, Parsec, SiderealDay,
+                SolarMass,
+            },
+            base::{kg, m, s, Radian},
+        },
+        value::Value,
+    },
+    SoftF64,
+};
+
+fn approx(lhs: f64, rhs: f64, tolerance: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < tolerance
+}
+
+#[test]
+fn test_distance_units() {
+    let parsec: Value<f64, m> = Value::<f64, Parsec>::new(1.0).to_metric();
+    assert!(approx(parsec.value, 3.085_677_581_491_367e16, 1e-15));
+    let au = Value::<f64, AstronomicalUnit>::from_metric(parsec);
+    let arcsec: Value<f64, Radian> = Value::<f64, Arcsecond>::new(1.0).to_metric();
+    assert!(approx(au.value * arcsec.value, 1.0, 1e-15));
+
+    let light_year: Value<u64, m> = Value::<u64, LightYear>::new(1).to_metric();
+    assert_eq!(light_year.value, 9_460_730_472_580_800);
+    let light_years = Value::<f64, LightYear>::from_metric(parsec);
+    assert!(approx(light_years.value, 3.261_563_777, 1e-9));
+}
+
+#[test]
+fn test_mass_units() {
+    let sun: Value<f64, kg> = Value::<f64, SolarMass>::new(1.0).to_metric();
+    assert!(approx(sun.value, 1.988_409_87e30, 1e-9));
+    let earth: Value<f64, kg> = Value::<f64, EarthMass>::new(1.0).to_metric();
+    assert!(approx(earth.value, 5.972_167_87e24, 1e-9));
+}
+
+#[test]
+fn test_time_units() {
+    let year: Value<u32, s> = Value::<u32, JulianYear>::new(1).to_metric();
+    assert_eq!(year.value, 31_557_600);
+    let days = Value::<f64, SiderealDay>::from_metric(Value::new(31_557_600.0));
+    assert!(approx(days.value, 366.25, 1e-4));
+}
+
+#[test]
+fn test_const_ephemeris() {
+    const SUN: Value<SoftF64, kg> = Value::<SoftF64, SolarMass>::new(SoftF64(1.0)).to_metric();
+    const DISTANCE: Value<SoftF64, m> = Value::<SoftF64, Parsec>::new(SoftF64(10.0)).to_metric();
+    const CENTURY: Value<SoftF64, s> =
+        Value::<SoftF64, JulianYear>::new(SoftF64(100.0)).to_metric();
+    assert!(approx(SUN.value.to_f64(), 1.988_409_87e30, 1e-9));
+    assert!(approx(DISTANCE.value.to_f64(), 3.085_677_581_491_367e17, 1e-15));
+    assert_eq!(CENTURY.value.to_f64(), 3_155_760_000.0);
+}
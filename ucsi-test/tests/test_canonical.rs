@@ -0,0 +1,65 @@
+use ucsi::{
+    core::{
+        ops::Scaled,
+        units::{
+            base::{kg, m, s},
+            canonical::Canonical,
+            exported::force::Newton,
+        },
+        value::Value,
+    },
+    unit, val, SiAnyUnit,
+};
+
+trait Describe {
+    const NAME: &'static str;
+}
+
+// a single impl covers every structural spelling of the unit
+impl Describe for Canonical<Newton> {
+    const NAME: &'static str = "force";
+}
+
+fn describe<U: Describe + SiAnyUnit>(_: &Value<f64, U>) -> &'static str {
+    U::NAME
+}
+
+#[test]
+fn test_canonical_type() {
+    type A = Canonical<unit!((kg * m) / (s ** { 2 }))>;
+    type B = Canonical<unit!(m * (kg / (s * s)))>;
+    let a: Value<f64, A> = Value::new(1.0);
+    let b: Value<f64, B> = Value::new(2.0);
+    // same rust type, so `ops::Add` works without casting
+    let sum = a + b;
+    assert_eq!(sum.value, 3.0);
+    assert_eq!(describe(&sum), "force");
+
+    let c = val!(2.0 * (m ** { 2 })).canonicalize();
+    let d: Value<f64, Canonical<unit!(m * m)>> = c;
+    assert_eq!(d.value, 2.0);
+}
+
+#[test]
+fn test_canonical_arith() {
+    let acc = val!(10.0 * (m / (s ** { 2 })));
+    let mass = val!(2.0 * kg);
+    let force = mass.mul_canonical(acc);
+    assert_eq!(describe(&force), "force");
+
+    let speed = val!(10.0 * m).div_canonical(val!(2.0 * s));
+    let speed: Value<f64, Canonical<unit!(m * (s ** { -1 }))>> = speed;
+    assert_eq!(speed.value, 5.0);
+}
+
+#[test]
+fn test_canonical_scaled_operand() {
+    // scaled operands must be rescaled to the coherent unit first
+    let km = Value::<f64, Scaled<m, 1000, 1>>::new(1.0);
+    let area = km.rescale::<m>().mul_canonical(val!(2.0 * m));
+    let area: Value<f64, Canonical<unit!(m ** { 2 })>> = area;
+    assert_eq!(area.value, 2000.0);
+
+    let speed = km.rescale::<m>().div_canonical(val!(4.0 * s));
+    assert_eq!(speed.value, 250.0);
+}
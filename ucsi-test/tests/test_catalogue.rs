@@ -0,0 +1,85 @@
+use std::{env, fs};
+
+use ucsi::{
+    catalogue::Catalogue,
+    core::units::{
+        associated::{
+            imperial::Fahrenheit,
+            nautical::{self, Fathom, Knot, NauticalMile},
+            temperature::{Celsius, Rankine},
+        },
+        convert::convert,
+        registry::{find_by_symbol, Registry, BUILTIN_UNITS},
+        scale::{LinearUnit, Scale, ScaledUnit},
+    },
+    units::{associated::degC, base::{m, Kelvins}},
+    Value,
+};
+
+/// Generated from `ucsi-test/catalogue` by the build script, like in a downstream crate.
+mod survey_units {
+    include!(concat!(env!("OUT_DIR"), "/catalogue.rs"));
+}
+
+#[test]
+fn test_catalogue_conversion() {
+    assert_eq!(Value::<i32, NauticalMile>::new(2).to_metric().value, 3704);
+    assert_eq!(Value::<f64, Fathom>::new(10.0).to_metric().value, 18.288);
+    assert_eq!(Value::<u32, Knot>::new(360).to_metric().value, 185);
+    assert_eq!(<Knot as ScaledUnit>::FACTOR, Scale::new(463, 900));
+
+    let boiling = Value::<f64, Celsius>::new(100.0).to_metric();
+    assert_eq!(boiling.value, 373.15);
+    let freezing = Value::<f64, degC>::from_metric(Value::<f64, Kelvins>::new(273.15));
+    assert_eq!(freezing.value, 0.0);
+
+    let rankine = Value::<f64, Rankine>::new(491.67).to_metric();
+    let fahrenheit = Value::<f64, Fahrenheit>::from_metric(rankine);
+    assert!((fahrenheit.value - 32.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_catalogue_registry() {
+    for entry in nautical::UNITS {
+        assert!(find_by_symbol(BUILTIN_UNITS, entry.unit_symbol).is_some());
+    }
+    let celsius = find_by_symbol(BUILTIN_UNITS, "°C").unwrap();
+    assert!(!celsius.is_linear());
+    assert_eq!(celsius.to_coherent(-273.15), 0.0);
+
+    assert!((convert(20.0, "kn", "km/h").unwrap() - 37.04).abs() < 1e-12);
+    assert!((convert(100.0, "°C", "°F").unwrap() - 212.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_catalogue_up_to_date() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../ucsi/catalogue");
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../ucsi/src/core/units/associated/catalogue.rs");
+    let source = Catalogue::builtin(dir).to_source().unwrap();
+    if env::var_os("UPDATE_CATALOGUE").is_some() {
+        fs::write(path, &source).unwrap();
+    }
+    assert!(
+        fs::read_to_string(path).unwrap() == source,
+        "the generated catalogue is out of date, rerun this test with `UPDATE_CATALOGUE=1`"
+    );
+}
+
+#[test]
+fn test_catalogue_downstream() {
+    use survey_units::survey::{aliases::ch, Chain, Smoot, UNITS};
+
+    assert_eq!(Value::<i32, ch>::new(5).rescale::<m>().value, 100);
+    assert!((Value::<f64, Chain>::new(10.0).rescale::<m>().value - 201.168).abs() < 1e-9);
+    assert_eq!(<Chain as ScaledUnit>::FACTOR, Scale::new(201_168, 10_000));
+    assert_eq!(<Smoot as LinearUnit>::FACTOR, 1.7018);
+    assert_eq!(UNITS.len(), 3);
+
+    let mut registry = Registry::builtin();
+    registry
+        .register_all(survey_units::CATALOGUE_UNITS.iter().flat_map(|units| units.iter().copied()))
+        .unwrap();
+    assert!((registry.convert(1.0, "ch", "ft").unwrap() - 66.0).abs() < 1e-9);
+    assert!((registry.convert(80.0, "°Re", "°C").unwrap() - 100.0).abs() < 1e-9);
+    assert!(convert(1.0, "ch", "m").is_err());
+}
@@ -0,0 +1,102 @@
+use ucsi::core::units::convert::ConvertError;
+use ucsi_cli::eval::{evaluate, format_number, EvalError, Style};
+
+fn eval(src: &str, style: Style) -> String {
+    evaluate(src).unwrap().render(style)
+}
+
+#[test]
+fn test_evaluate() {
+    assert_eq!(eval("3 kN * 2 m to J", Style::Plain), "6000 J");
+    assert_eq!(eval("3 kN * 2 m", Style::Plain), "6000 m^2*s^(-2)*kg");
+    assert_eq!(eval("sqrt(9.81 m/s^2 * 10 m)", Style::Plain), "9.90454441153 m*s^(-1)");
+    assert_eq!(eval("3 m / 2 s", Style::Plain), "1.5 m*s^(-1)");
+    assert_eq!(eval("12 mi/h to m/s", Style::Plain), "5.36448 m/s");
+    assert_eq!(eval("2 * (1 ft + 6 in) to cm", Style::Plain), "91.44 cm");
+    assert_eq!(eval("3 imp gal -> L", Style::Plain), "13.63827 L");
+    assert_eq!(eval("-2^2 + 1", Style::Plain), "-3");
+    assert_eq!(eval("1 m ** -1 * 2 m", Style::Plain), "2");
+    assert_eq!(eval("(4 m^2)^(1/2)", Style::Plain), "2 m");
+    assert_eq!(eval("ln(exp(2 m / 1 m))", Style::Plain), "2");
+}
+
+#[test]
+fn test_temperature() {
+    assert_eq!(eval("212 °F to K", Style::Plain), "373.15 K");
+    assert_eq!(eval("300 K to °F", Style::Plain), "80.33 °F");
+    // a temperature difference
+    assert_eq!(eval("9 * °F to K", Style::Plain), "5 K");
+}
+
+#[test]
+fn test_styles() {
+    assert_eq!(eval("3 kN * 2 m", Style::Unicode), "6000 m²·s⁻²·kg");
+    assert_eq!(eval("3 kN * 2 m", Style::Latex), "6000 \\, m^2 \\cdot s^{-2} \\cdot kg");
+    assert_eq!(eval("3 kN * 2 m to kJ", Style::Latex), "6 \\, \\mathrm{kJ}");
+    assert_eq!(eval("2 m / 4 m", Style::Unicode), "0.5");
+}
+
+#[test]
+fn test_errors() {
+    let error = evaluate("1 m + 2 s").unwrap_err();
+    assert!(matches!(error, EvalError::DimensionMismatch { op: "+", .. }));
+    assert_eq!(
+        error.to_string(),
+        "dimension mismatch in `+`: `m` and `s` (m: 1 vs 0, s: 0 vs 1)"
+    );
+
+    let error = evaluate("3 kN to W").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "dimension mismatch in `to`: `m*s^(-2)*kg` and `m^2*s^(-3)*kg` (m: 1 vs 2, s: -2 vs -3)"
+    );
+
+    assert_eq!(
+        evaluate("2 furlong"),
+        Err(EvalError::Unit {
+            position: 2,
+            error: ConvertError::UnknownUnit("furlong".into())
+        })
+    );
+    assert!(matches!(evaluate("sin(1 m)"), Err(EvalError::NotDimensionless { .. })));
+    assert!(matches!(evaluate("(1 m"), Err(EvalError::Syntax { position: 4, .. })));
+    assert!(matches!(evaluate("1 m ^ x"), Err(EvalError::Syntax { .. })));
+    assert!(matches!(evaluate("1 m $"), Err(EvalError::Syntax { position: 4, .. })));
+    assert!(matches!(evaluate("1 m to"), Err(EvalError::Syntax { .. })));
+    assert!(matches!(evaluate("1 m )"), Err(EvalError::Syntax { position: 4, .. })));
+}
+
+#[test]
+fn test_empty_input() {
+    assert!(matches!(evaluate(""), Err(EvalError::Syntax { position: 0, .. })));
+    assert!(matches!(evaluate("   "), Err(EvalError::Syntax { position: 3, .. })));
+    assert!(matches!(evaluate("1 m ^"), Err(EvalError::Syntax { position: 5, .. })));
+    assert!(matches!(evaluate("2 *"), Err(EvalError::Syntax { position: 3, .. })));
+}
+
+#[test]
+fn test_exponent_overflow() {
+    let error = evaluate("(m^100000)^100000").unwrap_err();
+    assert_eq!(
+        error,
+        EvalError::Syntax {
+            position: 11,
+            message: "unit exponent or scale factor overflow".into()
+        }
+    );
+    assert!(matches!(evaluate("(m^100000)^(100000/3)"), Err(EvalError::Syntax { .. })));
+    assert!(matches!(evaluate("(2 m^2)^2147483647"), Err(EvalError::Syntax { .. })));
+    assert_eq!(eval("m^999999999 / m^999999999", Style::Plain), "1");
+
+    assert!(matches!(evaluate("m^2147483647 * m"), Err(EvalError::Syntax { position: 13, .. })));
+    assert!(matches!(evaluate("m / m^-2147483647"), Err(EvalError::Syntax { position: 2, .. })));
+    assert!(matches!(evaluate("m^2147483647 m"), Err(EvalError::Syntax { position: 13, .. })));
+}
+
+#[test]
+fn test_format_number() {
+    assert_eq!(format_number(0.1 + 0.2), "0.3");
+    assert_eq!(format_number(1.5e20), "1.5e20");
+    assert_eq!(format_number(-2.5e-9), "-2.5e-9");
+    assert_eq!(format_number(0.0), "0");
+}
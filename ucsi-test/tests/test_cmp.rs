@@ -0,0 +1,103 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+};
+
+use ucsi::{
+    core::{
+        units::{
+            base::{kg, m, s},
+            exported::force::Newton,
+        },
+        value::Value,
+    },
+    unit, val,
+};
+
+#[test]
+fn test_same_unit_cmp() {
+    let a = val!(1.0 * m);
+    let b = val!(2.0 * m);
+    assert!(a < b);
+    assert!(a != b);
+    assert_eq!(a, val!(1.0 * m));
+    assert_eq!(Value::<f64, s>::default(), val!(0.0 * s));
+
+    assert_eq!(a.min(b), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(val!(3.0 * m).clamp(a, b), b);
+    assert_eq!(val!(0.0 * m).clamp(a, b), a);
+    assert_eq!(val!(1.5 * m).clamp(a, b), val!(1.5 * m));
+}
+
+#[test]
+fn test_min_max_like_std() {
+    // ties: `min` keeps `self`, `max` takes `other`
+    let zero = val!(0.0 * m);
+    let neg_zero = Value::<f64, m>::new(-0.0);
+    assert!(zero.min(neg_zero).value.is_sign_positive());
+    assert!(zero.max(neg_zero).value.is_sign_negative());
+    assert_eq!(val!(1 * kg).max(val!(1 * kg)), Ord::max(val!(1 * kg), val!(1 * kg)));
+
+    // `NaN` is ignored by `min` and `max`, but kept by `clamp`
+    let nan = Value::<f64, m>::new(f64::NAN);
+    let one = val!(1.0 * m);
+    assert_eq!(nan.min(one), one);
+    assert_eq!(one.min(nan), one);
+    assert_eq!(nan.max(one), one);
+    assert_eq!(one.max(nan), one);
+    assert!(nan.clamp(zero, one).value.is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_nan_bound() {
+    val!(1.0 * m).clamp(Value::<f64, m>::new(f64::NAN), val!(2.0 * m));
+}
+
+#[test]
+fn test_collections() {
+    let mut sorted: Vec<Value<i32, kg>> = vec![val!(3 * kg), val!(1 * kg), val!(2 * kg)];
+    sorted.sort();
+    assert_eq!(sorted, vec![val!(1 * kg), val!(2 * kg), val!(3 * kg)]);
+
+    let mut tree = BTreeMap::new();
+    tree.insert(val!(2 * s), "two");
+    tree.insert(val!(1 * s), "one");
+    assert_eq!(tree.values().copied().collect::<Vec<_>>(), vec!["one", "two"]);
+
+    let mut hash = HashMap::new();
+    hash.insert(val!(10 * m), "ten");
+    assert_eq!(hash.get(&val!(10 * m)), Some(&"ten"));
+}
+
+#[test]
+fn test_cross_unit_cmp() {
+    let force: Value<f64, unit!((kg * m) / (s ** { 2 }))> = Value::new(2.0);
+    let newton: Value<f64, Newton> = Value::new(3.0);
+    assert_eq!(force.partial_cmp_unit(&newton), Some(Ordering::Less));
+    assert_eq!(val!(1.0 * m).partial_cmp_unit(&val!(1.0 * s)), None);
+
+    let force: Value<i32, unit!((kg * m) / (s ** { 2 }))> = Value::new(3);
+    let newton: Value<i32, Newton> = Value::new(3);
+    assert_eq!(force.cmp_unit(&newton), Some(Ordering::Equal));
+    assert_eq!(val!(1 * m).cmp_unit(&val!(1 * kg)), None);
+}
+
+#[test]
+fn test_cast_arith() {
+    let force: Value<f64, unit!((kg * m) / (s ** { 2 }))> = Value::new(2.0);
+    let newton: Value<f64, Newton> = Value::new(3.0);
+
+    let sum: Value<f64, Newton> = newton.cast_add(force);
+    assert_eq!(sum, Value::new(5.0));
+    let diff: Value<f64, unit!((kg * m) / (s ** { 2 }))> = force.cast_sub(newton);
+    assert_eq!(diff, Value::new(-1.0));
+
+    assert!(sum.cast_eq(&Value::<f64, unit!(m * (kg / (s ** { 2 })))>::new(5.0)));
+    assert_eq!(force.cast_partial_cmp(&newton), Some(Ordering::Less));
+    assert_eq!(
+        val!(4 * (kg * m)).cast_cmp(&val!(4 * (m * kg))),
+        Ordering::Equal
+    );
+}
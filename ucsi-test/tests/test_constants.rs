@@ -0,0 +1,56 @@
+use ucsi::{
+    constants::{self, codata2018, codata2022},
+    core::{
+        units::{
+            base::{kg, m, mol, s, PureValue, A, K},
+            exported::energy::Joule,
+        },
+        value::Value,
+    },
+    unit, SoftF64,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < 1e-9
+}
+
+#[test]
+fn test_exact_constants() {
+    let hbar = constants::H.value / (2.0 * std::f64::consts::PI);
+    assert!(approx(constants::HBAR.value, hbar));
+    let r: Value<f64, unit!(Joule / (mol * K))> =
+        (constants::N_A * constants::K_B).cast();
+    assert!(approx(r.value, constants::R.value));
+    let f: Value<f64, unit!((A * s) / mol)> =
+        (constants::E * constants::N_A).cast();
+    assert!(approx(f.value, constants::F.value));
+}
+
+#[test]
+fn test_codata_constants() {
+    let alpha: Value<f64, PureValue> = codata2022::ALPHA;
+    assert!(approx(1.0 / alpha.value, 137.035_999_177));
+    assert_ne!(codata2018::M_E, codata2022::M_E);
+    assert_eq!(codata2018::G, codata2022::G);
+    let relative = codata2022::uncertainty::M_E.value / codata2022::M_E.value;
+    assert!(relative < 1e-9);
+
+    // a product has no kind, attaching the energy kind needs `reinterpret_kind`
+    let rest_energy: Value<f64, Joule> = (codata2022::M_E * constants::C * constants::C).reinterpret_kind();
+    assert!(approx(rest_energy.value, 8.187_105_788e-14));
+
+    // μ_0 ε_0 c^2 = 1
+    let one: Value<f64, PureValue> =
+        (codata2022::MU_0 * codata2022::EPSILON_0 * constants::C * constants::C).cast();
+    assert!((one.value - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_soft_constants() {
+    const G_0: Value<SoftF64, unit!(m / (s ** { 2 }))> = constants::soft::G_0;
+    const M_P: Value<SoftF64, kg> = codata2022::soft::M_P;
+    const M_P_UNC: Value<SoftF64, kg> = codata2022::soft::uncertainty::M_P;
+    assert_eq!(G_0.value.to_f64(), constants::G_0.value);
+    assert_eq!(M_P.value.to_f64(), codata2022::M_P.value);
+    assert_eq!(M_P_UNC.value.to_f64(), codata2022::uncertainty::M_P.value);
+}
@@ -0,0 +1,139 @@
+use ucsi::core::{
+    dyn_value::DynValue,
+    units::{
+        any::SiOpsUnit,
+        base::{Meter, Second},
+        convert::{convert, parse_unit, ConvertError},
+        registry::{Registry, BUILTIN_UNITS},
+    },
+};
+use ucsi::unit;
+
+type MeterPerSecond = unit!(Meter / Second);
+
+fn assert_close(lhs: f64, rhs: f64) {
+    assert!(
+        (lhs - rhs).abs() <= 1e-12 * rhs.abs().max(1.0),
+        "{} != {}",
+        lhs,
+        rhs
+    );
+}
+
+#[test]
+fn test_convert_linear() {
+    assert_close(convert(12.0, "mi/h", "m/s").unwrap(), 5.364_48);
+    assert_close(convert(1.0, "km", "ft").unwrap(), 1000.0 / 0.3048);
+    assert_close(convert(1.0, "kWh", "MJ").unwrap(), 3.6);
+    assert_close(convert(1.0, "atm", "hPa").unwrap(), 1013.25);
+    assert_close(convert(1.0, "N m", "J").unwrap(), 1.0);
+    assert_close(convert(3.0, "imp gal", "L").unwrap(), 13.638_27);
+    assert_close(convert(1.0, "hour", "min").unwrap(), 60.0);
+}
+
+#[test]
+fn test_convert_syntax() {
+    assert_close(convert(1.0, "kg*m^2/s^2", "J").unwrap(), 1.0);
+    assert_close(convert(1.0, "kg·m**2·s**(-2)", "kJ").unwrap(), 1e-3);
+    assert_close(convert(1.0, "m/s/s", "m/s^2").unwrap(), 1.0);
+    assert_close(convert(1.0, "J/(kg*K)", "J/kg/K").unwrap(), 1.0);
+    assert_close(convert(60.0, "1/min", "Hz").unwrap(), 1.0);
+    assert_close(convert(1.0, "mm^3", "L").unwrap(), 1e-6);
+    assert_close(convert(1.0, "ms", "µs").unwrap(), 1000.0);
+    // U+03BC, the Greek small letter mu
+    assert_close(convert(1.0, "ms", "\u{3bc}s").unwrap(), 1000.0);
+    assert_close(convert(1.0, "km^-3", "m^-3").unwrap(), 1e-9);
+}
+
+#[test]
+fn test_convert_affine() {
+    assert_close(convert(212.0, "°F", "K").unwrap(), 373.15);
+    assert_close(convert(273.15, "K", "°F").unwrap(), 32.0);
+    // a temperature difference inside compound expressions
+    assert_close(convert(9.0, "°F/s", "K/s").unwrap(), 5.0);
+}
+
+#[test]
+fn test_convert_errors() {
+    assert_eq!(
+        convert(1.0, "mi", "s"),
+        Err(ConvertError::DimensionMismatch {
+            from: "mi".into(),
+            to: "s".into()
+        })
+    );
+    assert_eq!(
+        convert(1.0, "m/furlong", "1"),
+        Err(ConvertError::UnknownUnit("furlong".into()))
+    );
+    // logarithmic units and prefixed non-SI units are not linear units
+    assert!(matches!(
+        convert(1.0, "dBm", "W"),
+        Err(ConvertError::UnknownUnit(_))
+    ));
+    assert!(matches!(
+        convert(1.0, "kmi", "m"),
+        Err(ConvertError::UnknownUnit(_))
+    ));
+
+    assert!(matches!(
+        convert(1.0, "m/", "m"),
+        Err(ConvertError::Syntax { position: 2, .. })
+    ));
+    assert!(matches!(
+        convert(1.0, "(m", "m"),
+        Err(ConvertError::Syntax { .. })
+    ));
+    assert!(matches!(
+        convert(1.0, "m^x", "m"),
+        Err(ConvertError::Syntax { .. })
+    ));
+    assert!(matches!(
+        convert(1.0, "m)", "m"),
+        Err(ConvertError::Syntax { .. })
+    ));
+}
+
+#[test]
+fn test_convert_invalid_numbers() {
+    for expr in ["NaN", "inf", "-infinity", "0 m", "0/s", "1e999 m"] {
+        assert!(
+            matches!(parse_unit(BUILTIN_UNITS, expr), Err(ConvertError::InvalidFactor(_))),
+            "{}",
+            expr
+        );
+    }
+    assert_eq!(
+        convert(1.0, "NaN", "1").unwrap_err().to_string(),
+        "invalid factor `NaN`, expected a finite non-zero number"
+    );
+}
+
+#[test]
+fn test_convert_exponent_overflow() {
+    for expr in ["m^2^2", "(m^100000)^100000", "(m^2)^2147483647", "km^999999999", "m^-2147483648^-1"] {
+        let result = parse_unit(BUILTIN_UNITS, expr);
+        assert!(matches!(result, Err(ConvertError::Syntax { .. })), "{}: {:?}", expr, result);
+    }
+    assert!(matches!(
+        parse_unit(BUILTIN_UNITS, "(m^100000)^100000"),
+        Err(ConvertError::Syntax { position: 11, message: "exponent overflow" })
+    ));
+    assert!(parse_unit(BUILTIN_UNITS, "m^999999999").is_ok());
+}
+
+#[test]
+fn test_parse_to_dyn() {
+    let speed = parse_unit(BUILTIN_UNITS, "km/h").unwrap();
+    let value = speed.to_dyn(36.0);
+    assert_close(value.value, 10.0);
+    assert!(value.is_same_unit(&DynValue::of::<MeterPerSecond>(0.0)));
+    assert!(speed.unit_map.eq(&<MeterPerSecond as SiOpsUnit>::UNIT_MAP));
+}
+
+#[test]
+fn test_registry_convert() {
+    let registry = Registry::builtin();
+    assert_close(registry.convert(1.0, "yd", "in").unwrap(), 36.0);
+    assert!(registry.parse("furlong").is_err());
+}
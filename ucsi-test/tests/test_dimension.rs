@@ -0,0 +1,44 @@
+use ucsi::{
+    core::{
+        units::{
+            base::{kg, m, s},
+            dimension::{Dimension, SameDimension},
+            exported::force::Newton,
+        },
+        value::Value,
+    },
+    unit, val,
+};
+
+fn total_force<A: SameDimension<Newton>, B: SameDimension<Newton>>(
+    a: Value<f64, A>,
+    b: Value<f64, B>,
+) -> Value<f64, Newton> {
+    a.cast_dim::<Newton>().add_dim(b)
+}
+
+fn meter_exponent<U: Dimension>() -> i32 {
+    <U::Meter as typenum::Integer>::I32
+}
+
+#[test]
+fn test_same_dimension() {
+    type A = unit!((kg * m) / (s ** { 2 }));
+    type B = unit!(m * (kg / (s * s)));
+    let a: Value<f64, A> = Value::new(1.0);
+    let b: Value<f64, B> = Value::new(2.0);
+    assert_eq!(total_force(a, b).value, 3.0);
+
+    let diff = val!(5.0 * (m * s)).sub_dim(val!(2.0 * (s * m)));
+    assert_eq!(diff.value, 3.0);
+
+    const CAST: Value<i32, unit!(s * m)> = val!(1 * (m * s)).cast_dim_const();
+    assert_eq!(CAST.value, 1);
+}
+
+#[test]
+fn test_exponents() {
+    assert_eq!(meter_exponent::<Newton>(), 1);
+    assert_eq!(meter_exponent::<unit!(m ** { -3 })>(), -3);
+    assert_eq!(meter_exponent::<kg>(), 0);
+}
@@ -0,0 +1,141 @@
+use datastruct::DataStruct;
+use ucsi::{
+    core::{
+        dyn_value::{DynError, DynValue},
+        format::ufmt::{SiDefault, SiDefaultOption, SiUnicode, SiUnicodeOption},
+        units::{
+            any::SiOpsUnit,
+            base::{kg, m, s, BaseUnitMap},
+            exported::{energy::J, force::N, torque::NewtonMeter},
+            extra::ExtraDimensions,
+            scale::Scale,
+        },
+        value::Value,
+    },
+    fraction::Fraction,
+    ops::Scaled,
+    unit,
+};
+
+type Km = Scaled<m, 1000, 1>;
+
+#[test]
+fn test_dyn_arithmetic() {
+    let length = DynValue::of::<m>(6.0_f64);
+    let time = DynValue::of::<s>(2.0);
+    let speed = (length / time).unwrap();
+    assert_eq!(speed.value, 3.0);
+    assert!(speed.unit().eq(&<unit!(m / s) as SiOpsUnit>::UNIT_MAP));
+
+    let sum = (speed + DynValue::from(Value::<f64, unit!(m / s)>::new(1.0))).unwrap();
+    assert_eq!(sum.value, 4.0);
+    let diff = (speed - speed).unwrap();
+    assert_eq!(diff.value, 0.0);
+    assert_eq!((-speed).value, -3.0);
+
+    let area = length.powi(2).unwrap();
+    assert!(area.unit().eq(&<unit!(m ** { 2 }) as SiOpsUnit>::UNIT_MAP));
+}
+
+#[test]
+fn test_dyn_mismatch() {
+    let length = DynValue::of::<m>(1.0_f64);
+    let mass = DynValue::of::<kg>(1.0);
+    assert_eq!(length + mass, Err(DynError::DimensionMismatch));
+    assert!((length - mass).is_err());
+
+    let km = DynValue::of::<Km>(1.0_f64);
+    assert_eq!(
+        length + km,
+        Err(DynError::ScaleMismatch {
+            expected: Scale::ONE,
+            found: Scale::new(1000, 1),
+        })
+    );
+    assert_eq!((length + km.to_coherent()).unwrap().value, 1001.0);
+    assert_eq!(length.rescale(Scale::new(1000, 1)).value, 0.001);
+    assert_eq!((length + km).unwrap_err().to_string(), "scale mismatch: expected `1`, found `1000`");
+}
+
+#[test]
+fn test_dyn_powi_overflow() {
+    let length = DynValue::of::<m>(2.0_f64);
+    assert_eq!(length.powi(2).unwrap().powi(i32::MAX).unwrap_err(), DynError::Overflow);
+    assert_eq!(length.powi(-1).unwrap().powi(i32::MIN).unwrap_err(), DynError::Overflow);
+    assert_eq!(DynValue::of::<Km>(1.0_f64).powi(20).unwrap_err(), DynError::Overflow);
+    assert_eq!(DynValue::of::<Km>(1.0_f64).powi(-4).unwrap().scale(), Scale::new(1, 1_000_000_000_000));
+    assert_eq!(DynValue::dimensionless(2.0_f64).powi(999_999_999).unwrap().scale(), Scale::ONE);
+    assert_eq!(length.powi(100_000).unwrap().powf(100_000, 3).unwrap_err(), DynError::Overflow);
+}
+
+#[test]
+fn test_dyn_mul_overflow() {
+    let length = DynValue::of::<m>(2.0_f64);
+    let huge = length.powi(i32::MAX).unwrap();
+    assert_eq!((huge * length).unwrap_err(), DynError::Overflow);
+    assert_eq!((length / huge.powi(-1).unwrap()).unwrap_err(), DynError::Overflow);
+    assert_eq!((huge / huge).unwrap().unit(), &BaseUnitMap::EMPTY);
+
+    let km = DynValue::of::<Km>(1.0_f64).powi(12).unwrap();
+    assert_eq!((km * km).unwrap_err(), DynError::Overflow);
+    assert_eq!((km / km.powi(-1).unwrap()).unwrap_err(), DynError::Overflow);
+
+    let dims = |ids: &[&'static str]| {
+        let mut extra = ExtraDimensions::EMPTY;
+        for id in ids {
+            extra = extra.insert(id, id, Fraction::ONE);
+        }
+        DynValue::new(1.0_f64, BaseUnitMap { extra, ..BaseUnitMap::EMPTY })
+    };
+    assert_eq!((dims(&["a", "b", "c"]) * dims(&["d", "e"])).unwrap_err(), DynError::Overflow);
+    assert_eq!((dims(&["a", "b", "c"]) / dims(&["a", "d"])).unwrap().unit().extra.len(), 3);
+}
+
+#[test]
+fn test_dyn_downcast() {
+    let work = (DynValue::of::<N>(2.0) * DynValue::of::<m>(3.0)).unwrap();
+    let work: Value<f64, J> = work.downcast().unwrap();
+    assert_eq!(work.value, 6.0);
+
+    let force = DynValue::from(Value::<i32, N>::new(5));
+    assert!(force.check::<unit!((kg * m) / (s ** { 2 }))>().is_ok());
+    assert!(matches!(force.downcast::<kg>(), Err(DynError::DimensionMismatch)));
+    assert!(matches!(
+        DynValue::of::<Km>(1).downcast::<m>(),
+        Err(DynError::ScaleMismatch { .. })
+    ));
+    assert_eq!(DynValue::of::<Km>(7).downcast::<Km>().unwrap().value, 7);
+
+    // kinds are not tracked at runtime
+    let torque = DynValue::from(Value::<f64, NewtonMeter>::new(4.0));
+    assert_eq!(torque.downcast::<J>().unwrap().value, 4.0);
+
+    let raw = DynValue::new(1.5, BaseUnitMap::EMPTY);
+    assert_eq!(raw, DynValue::dimensionless(1.5));
+    assert!(raw.map(|v| v as i32).is_same_unit(&DynValue::dimensionless(0u8)));
+}
+
+#[test]
+fn test_dyn_display() {
+    let speed = (DynValue::of::<Km>(72.0) / DynValue::of::<s>(1.0)).unwrap();
+    assert_eq!(speed.to_string(), "72 1000 * m + s^(-1)");
+    // the unit is written by `SiDefault`
+    let unit = speed.fmt_unit::<SiDefault>(SiDefaultOption::data_default());
+    assert_eq!(speed.to_string(), format!("72 {}", unit));
+    assert_eq!(DynValue::dimensionless(2).to_string(), "2");
+    assert_eq!(DynValue::with_scale(2, BaseUnitMap::EMPTY, Scale::new(1, 100)).to_string(), "2 1/100 * 1");
+}
+
+#[test]
+fn test_dyn_powf_and_format() {
+    let area = DynValue::of::<unit!(m * m)>(9.0_f64);
+    let side = area.powf(1, 2).unwrap();
+    assert_eq!(side.value, 3.0);
+    assert!(side.check::<m>().is_ok());
+
+    let speed = DynValue::of::<unit!(m / s)>(2.0_f64);
+    assert_eq!(speed.fmt_unit::<SiDefault>(SiDefaultOption::data_default()), "m + s^(-1)");
+    assert_eq!(speed.fmt_unit::<SiUnicode>(SiUnicodeOption::data_default()), "m·s⁻¹");
+    let sqrt = speed.powf(1, 2).unwrap();
+    assert_eq!(sqrt.fmt_unit::<SiUnicode>(SiUnicodeOption::data_default()), "m¹ᐟ²·s⁻¹ᐟ²");
+}
@@ -0,0 +1,76 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::{
+                energy::{BritishThermalUnit, Calorie, Erg, Kilocalorie, KilowattHour},
+                power::{MechanicalHorsepower, MetricHorsepower},
+                pressure::{
+                    Atmosphere, Bar, Millibar, MillimeterOfMercury, PoundPerSquareInch, Torr,
+                },
+            },
+            base::s,
+            exported::{energy::J, power::W, pressure::Pa},
+            any::is_same_type,
+            system::cgs::{self, Cgs},
+        },
+        value::Value,
+    },
+    SoftF64,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < 1e-12
+}
+
+#[test]
+fn test_pressure_units() {
+    let pressure: Value<u32, Pa> = Value::<u32, Bar>::new(2).to_metric();
+    assert_eq!(pressure.value, 200_000);
+    assert_eq!(Value::<u32, Millibar>::from_metric(pressure).value, 2_000);
+    let atm: Value<i64, Pa> = Value::<i64, Atmosphere>::new(1).to_metric();
+    assert_eq!(atm.value, 101_325);
+    assert_eq!(Value::<i64, Torr>::from_metric(atm).value, 760);
+
+    let torr: Value<f64, Pa> = Value::<f64, Torr>::new(1.0).to_metric();
+    let mmhg: Value<f64, Pa> = Value::<f64, MillimeterOfMercury>::new(1.0).to_metric();
+    assert!(approx(mmhg.value, 133.322_387_415));
+    assert!((torr.value / mmhg.value - 1.0).abs() < 1e-6);
+
+    let psi = Value::<f64, PoundPerSquareInch>::from_metric(Value::new(101_325.0));
+    assert!((psi.value - 14.695_948_8).abs() < 1e-6);
+
+    const PRESSURE: Value<SoftF64, Pa> = Value::<SoftF64, Bar>::new(SoftF64(1.5)).to_metric();
+    assert_eq!(PRESSURE.value.to_f64(), 150_000.0);
+}
+
+#[test]
+fn test_energy_units() {
+    let energy: Value<u64, J> = Value::<u64, KilowattHour>::new(2).to_metric();
+    assert_eq!(energy.value, 7_200_000);
+    assert_eq!(Value::<u64, Kilocalorie>::from_metric(Value::new(8_368)).value, 2);
+    let calorie: Value<f64, J> = Value::<f64, Calorie>::new(1000.0).to_metric();
+    assert!(approx(calorie.value, 4184.0));
+    let erg: Value<f64, J> = Value::<f64, Erg>::new(1.0).convert_system::<Cgs, _>();
+    assert!(approx(erg.value, 1e-7));
+    assert!(is_same_type::<Erg, cgs::Erg>());
+    let btu = Value::<f64, BritishThermalUnit>::from_metric(Value::new(3_600_000.0));
+    assert!((btu.value - 3_412.141_6).abs() < 1e-4);
+
+    const ENERGY: Value<SoftF64, J> = Value::<SoftF64, Kilocalorie>::new(SoftF64(0.5)).to_metric();
+    assert_eq!(ENERGY.value.to_f64(), 2_092.0);
+}
+
+#[test]
+fn test_power_units() {
+    let metric: Value<f64, W> = Value::<f64, MetricHorsepower>::new(1.0).to_metric();
+    assert!(approx(metric.value, 735.498_75));
+    let mechanical: Value<f64, W> = Value::<f64, MechanicalHorsepower>::new(1.0).to_metric();
+    assert!(approx(mechanical.value, 745.699_871_582_270_2));
+
+    let power: Value<u32, W> = Value::<u32, MetricHorsepower>::new(100).to_metric();
+    assert_eq!(power.value, 73_549);
+
+    let work: Value<f64, J> = (mechanical * Value::<f64, s>::new(3600.0)).reinterpret_kind();
+    let kwh = Value::<f64, KilowattHour>::from_metric(work);
+    assert!(approx(kwh.value, 0.745_699_871_582_270_2));
+}
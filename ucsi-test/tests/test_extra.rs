@@ -0,0 +1,114 @@
+use datastruct::DataStruct;
+use ucsi::{
+    core::{
+        format::ufmt::{SiDefault, SiDefaultOption},
+        units::{
+            any::{is_same_type, SiOpsUnit},
+            base::{m, s},
+            extra::ExtraDimensions,
+        },
+        value::Value,
+    },
+    fraction::Fraction,
+    macros::unit_def::si_base_unit_def,
+    unit, val,
+};
+
+use aliases::px;
+
+si_base_unit_def! {
+    alias pub mod aliases;
+
+    /// Information.
+    unit Bit {
+        full_name: "Bit",
+        short_name: "bit",
+        unit_symbol: "bit",
+    } alias bit;
+
+    /// Count of events.
+    unit Event {
+        full_name: "Event",
+        short_name: "event",
+        unit_symbol: "event",
+    };
+
+    unit Pixel {
+        full_name: "Pixel",
+        short_name: "pixel",
+        unit_symbol: "px",
+    } alias px;
+}
+
+#[test]
+fn test_extra_dimension() {
+    assert!(is_same_type::<Bit, aliases::bit>());
+    assert!(!is_same_type::<Bit, Event>());
+    assert!(!is_same_type::<Bit, m>());
+    assert!(is_same_type::<unit!(Bit * Pixel), unit!(Pixel * Bit)>());
+    assert!(is_same_type::<unit!((Bit * Event) / Event), Bit>());
+
+    let map = <unit!((Bit ** { 2 }) / s)>::UNIT_MAP;
+    assert_eq!(map.extra.len(), 1);
+    assert!(map.extra.get_unit::<Bit>().eq(&Fraction::new(2, 1)));
+    assert!(map.second.eq(&Fraction::NEG_ONE));
+}
+
+#[test]
+fn test_extra_ops() {
+    let size = val!(8.0 * Bit);
+    let time = val!(2.0 * s);
+    let rate: Value<f64, unit!(Bit / s)> = size / time;
+    let per_pixel: Value<f64, unit!(Bit / (s * px))> = (rate / val!(2.0 * px)).cast();
+    assert_eq!(per_pixel.value, 2.0);
+
+    let total = (per_pixel * val!(2.0 * px) * time).cast_const::<Bit>() + size;
+    assert_eq!(total.value, 16.0);
+}
+
+#[test]
+fn test_extra_format() {
+    let rate = val!(8.0 * Bit) / val!(2.0 * s);
+    let unit = rate.fmt_unit::<SiDefault>(SiDefaultOption::data_default());
+    assert_eq!(unit.to_string(), "s^(-1) + bit");
+}
+
+mod other {
+    use ucsi::macros::unit_def::si_base_unit_def;
+
+    si_base_unit_def! {
+        alias pub mod aliases;
+
+        /// Another unit with the symbol of [`super::Bit`].
+        unit Bit {
+            full_name: "Bit",
+            short_name: "bit",
+            unit_symbol: "bit",
+        };
+    }
+}
+
+#[test]
+fn test_extra_same_symbol() {
+    assert!(!is_same_type::<Bit, other::Bit>());
+    assert!(!is_same_type::<Bit, ucsi::units::associated::information::Bit>());
+    type OtherBit = other::Bit;
+    let map = <unit!(Bit / OtherBit)>::UNIT_MAP;
+    assert_eq!(map.extra.len(), 2);
+    assert!(map.extra.get_unit::<other::Bit>().eq(&Fraction::NEG_ONE));
+}
+
+#[test]
+#[should_panic(expected = "at most `ExtraDimensions::CAPACITY` are supported")]
+fn test_extra_capacity() {
+    let mut dims = ExtraDimensions::EMPTY;
+    for id in ["a", "b", "c", "d", "e"] {
+        dims = dims.insert(id, id, Fraction::ONE);
+    }
+}
+
+#[test]
+fn test_extra_capacity_value() {
+    // the capacity is named in the panic message
+    assert_eq!(ExtraDimensions::CAPACITY, 4);
+}
@@ -0,0 +1,108 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::{
+                imperial::{
+                    BritishThermalUnit, Fahrenheit, Foot, ImperialGallon, ImperialPint, Inch,
+                    Mile, Pound, PoundPerSquareInch, Stone,
+                },
+                us_customary::{UsFluidOunce, UsGallon, UsPint},
+            },
+            base::{kg, m, Kelvins},
+            exported::{energy::Joule, pressure::Pa},
+        },
+        value::Value,
+    },
+    unit, SoftF64,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < 1e-12
+}
+
+#[test]
+fn test_length_units() {
+    let foot: Value<f64, m> = Value::<f64, Inch>::new(12.0).to_metric();
+    assert!(approx(foot.value, 0.3048));
+    assert!(approx(foot.value, Value::<f64, Foot>::new(1.0).to_metric().value));
+
+    let mile: Value<u64, m> = Value::<u64, Mile>::new(5).to_metric();
+    assert_eq!(mile.value, 8_046);
+    assert_eq!(Value::<i32, Inch>::from_metric(Value::new(254)).value, 10_000);
+
+    const MILE: Value<SoftF64, m> = Value::<SoftF64, Mile>::new(SoftF64(1.0)).to_metric();
+    assert!(approx(MILE.value.to_f64(), 1609.344));
+}
+
+#[test]
+fn test_narrow_integer_units() {
+    let mile: Value<u16, m> = Value::<u16, Mile>::new(40).to_metric();
+    assert_eq!(mile.value, 64_373);
+    assert_eq!(Value::<i16, Mile>::new(-20).to_metric().value, -32_186);
+    assert_eq!(Value::<u16, Mile>::from_metric(Value::new(65_535)).value, 40);
+
+    const INCH: Value<i16, m> = Value::<i16, Inch>::new(-3937).to_metric();
+    assert_eq!(INCH.value, -99);
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_narrow_integer_overflow() {
+    let _ = Value::<u16, Mile>::new(100).to_metric();
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_narrow_integer_overflow_from_metric() {
+    let _ = Value::<i16, Inch>::from_metric(Value::new(1_000));
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_wide_integer_overflow() {
+    let _ = Value::<u128, Mile>::new(u128::MAX).to_metric();
+}
+
+#[test]
+fn test_mass_units() {
+    let pound: Value<f64, kg> = Value::<f64, Pound>::new(1.0).to_metric();
+    assert!(approx(pound.value, 0.453_592_37));
+    let stone: Value<f64, kg> = Value::<f64, Stone>::new(1.0).to_metric();
+    assert!(approx(stone.value, 14.0 * pound.value));
+}
+
+#[test]
+fn test_volume_units() {
+    let us: Value<f64, unit!(m ** { 3 })> = Value::<f64, UsGallon>::new(1.0).to_metric();
+    let imperial: Value<f64, unit!(m ** { 3 })> =
+        Value::<f64, ImperialGallon>::new(1.0).to_metric();
+    assert!(approx(us.value, 3.785_411_784e-3));
+    assert!(approx(imperial.value, 4.546_09e-3));
+
+    let pints = Value::<f64, UsPint>::from_metric(us);
+    assert!(approx(pints.value, 8.0));
+    let pints = Value::<f64, ImperialPint>::from_metric(imperial);
+    assert!(approx(pints.value, 8.0));
+    let ounces = Value::<f64, UsFluidOunce>::from_metric(us);
+    assert!(approx(ounces.value, 128.0));
+}
+
+#[test]
+fn test_pressure_energy_units() {
+    let psi: Value<f64, Pa> = Value::<f64, PoundPerSquareInch>::new(1.0).to_metric();
+    assert!((psi.value - 6_894.757_293).abs() < 1e-6);
+    let btu: Value<f64, Joule> = Value::<f64, BritishThermalUnit>::new(1.0).to_metric();
+    assert!(approx(btu.value, 1_055.055_852_62));
+}
+
+#[test]
+fn test_fahrenheit() {
+    let freezing: Value<f64, Kelvins> = Value::<f64, Fahrenheit>::new(32.0).to_metric();
+    assert!(approx(freezing.value, 273.15));
+    let boiling = Value::<f64, Fahrenheit>::from_metric(Value::new(373.15));
+    assert!(approx(boiling.value, 212.0));
+
+    const ZERO: Value<SoftF64, Kelvins> =
+        Value::<SoftF64, Fahrenheit>::new(SoftF64(-459.67)).to_metric();
+    assert_eq!(ZERO.value.to_f64(), 0.0);
+}
@@ -0,0 +1,53 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::information::{
+                Bit, BitPerSecond, Byte, BytePerSecond, Gibibyte, Kibibyte, Kilobit, Kilobyte,
+                Mebibyte, Megabit, MegabitPerSecond, Megabyte, Pebibyte,
+            },
+            base::s,
+        },
+        value::Value,
+    },
+    unit,
+};
+
+#[test]
+fn test_byte_prefixes() {
+    let bits: Value<u64, Bit> = Value::<u64, Byte>::new(3).to_metric();
+    assert_eq!(bits.value, 24);
+    let bits: Value<u64, Bit> = Value::<u64, Kilobit>::new(2).to_metric();
+    assert_eq!(bits.value, 2_000);
+
+    let bits: Value<u64, Bit> = Value::<u64, Mebibyte>::new(1).to_metric();
+    assert_eq!(bits.value, 8 * 1024 * 1024);
+    assert_eq!(Value::<u64, Kibibyte>::from_metric(bits).value, 1024);
+    assert_eq!(Value::<u64, Megabit>::from_metric(bits).value, 8);
+
+    let bits: Value<u128, Bit> = Value::<u128, Pebibyte>::new(1).to_metric();
+    assert_eq!(bits.value, 8 << 50);
+}
+
+#[test]
+fn test_const_conversion() {
+    const BITS: Value<u64, Bit> = Value::<u64, Gibibyte>::new(1).to_metric();
+    const MB: Value<u64, Megabyte> = Value::<u64, Megabyte>::from_metric(BITS);
+    const KB: Value<u64, Kilobyte> = Value::<u64, Kilobyte>::from_metric(BITS);
+    assert_eq!(MB.value, 1_073);
+    assert_eq!(KB.value, 1_073_741);
+
+    let bits = Value::<f64, Megabyte>::new(1.5).to_metric();
+    assert_eq!(bits.value, 12_000_000.0);
+}
+
+#[test]
+fn test_data_rate() {
+    let size: Value<u64, Bit> = Value::<u64, Megabyte>::new(10).to_metric();
+    let time: Value<u64, s> = Value::new(8);
+    let rate: Value<u64, unit!(Bit / s)> = size / time;
+    assert_eq!(Value::<u64, MegabitPerSecond>::from_metric(rate).value, 10);
+    assert_eq!(Value::<u64, BytePerSecond>::from_metric(rate).value, 1_250_000);
+
+    let rate: Value<u64, BitPerSecond> = Value::<u64, BytePerSecond>::new(100).to_metric();
+    assert_eq!(rate.value, 800);
+}
@@ -0,0 +1,84 @@
+use ucsi::{
+    core::{
+        units::{
+            any::{can_cast_kind, is_same_kind, SiOpsUnit},
+            base::{kg, m, s, PureValue},
+            exported::{
+                energy::Joule,
+                force::Newton,
+                frequency::Hertz,
+                radiation::{Becquerel, Gray, Sievert},
+                torque::NewtonMeter,
+            },
+            kind,
+        },
+        value::Value,
+    },
+    unit,
+};
+
+type RawEnergy = unit!((kg * (m ** { 2 })) / (s ** { 2 }));
+
+#[test]
+fn test_kind_def() {
+    assert_eq!(Joule::KIND, Some(kind::ENERGY));
+    assert_eq!(NewtonMeter::KIND, Some(kind::TORQUE));
+    assert_eq!(<unit!(Newton * m)>::KIND, None);
+
+    assert!(!is_same_kind::<Joule, unit!(Newton * m)>());
+    assert!(is_same_kind::<unit!(Newton * m), RawEnergy>());
+    assert!(!is_same_kind::<Joule, NewtonMeter>());
+    assert!(!is_same_kind::<Hertz, Becquerel>());
+    assert!(!is_same_kind::<Gray, Sievert>());
+}
+
+#[test]
+fn test_kind_cast_direction() {
+    // casting strips a kind, but never attaches one
+    assert!(can_cast_kind::<Joule, RawEnergy>());
+    assert!(can_cast_kind::<NewtonMeter, RawEnergy>());
+    assert!(!can_cast_kind::<RawEnergy, Joule>());
+    assert!(!can_cast_kind::<RawEnergy, NewtonMeter>());
+    assert!(!can_cast_kind::<Joule, NewtonMeter>());
+    assert!(can_cast_kind::<Joule, Joule>());
+}
+
+#[test]
+fn test_kind_cast() {
+    let work: Value<f64, unit!(Newton * m)> = Value::new(2.0);
+    let energy: Value<f64, Joule> = work.reinterpret_kind();
+    let torque: Value<f64, NewtonMeter> = work.reinterpret_kind_const();
+    assert_eq!(energy.value, torque.value);
+
+    let back: Value<f64, RawEnergy> = energy.cast();
+    assert_eq!(back.value, 2.0);
+
+    let reinterpreted: Value<f64, Joule> = torque.reinterpret_kind();
+    assert_eq!(reinterpreted.value, 2.0);
+    let activity: Value<f64, Becquerel> = Value::<f64, Hertz>::new(1.0).reinterpret_kind_const();
+    assert_eq!(activity.value, 1.0);
+}
+
+#[test]
+fn test_kind_cmp() {
+    let energy: Value<f64, Joule> = Value::new(2.0);
+    assert_eq!(energy.partial_cmp_unit(&Value::<f64, NewtonMeter>::new(2.0)), None);
+    assert_eq!(
+        energy.partial_cmp_unit(&Value::<f64, RawEnergy>::new(2.0)),
+        energy.cast_partial_cmp(&Value::<f64, RawEnergy>::new(2.0))
+    );
+}
+
+#[test]
+fn test_kind_propagation() {
+    assert_eq!(<unit!(Joule * PureValue)>::KIND, Some(kind::ENERGY));
+    assert_eq!(<unit!(PureValue * NewtonMeter)>::KIND, Some(kind::TORQUE));
+    assert_eq!(<unit!(Hertz / PureValue)>::KIND, Some(kind::FREQUENCY));
+    assert_eq!(<unit!(Joule ** { 1 })>::KIND, Some(kind::ENERGY));
+    assert_eq!(<unit!(Joule / s)>::KIND, None);
+    assert_eq!(<unit!(Joule ** { 2 })>::KIND, None);
+
+    let energy: Value<f64, Joule> = Value::new(3.0);
+    let scaled: Value<f64, Joule> = (energy * Value::<f64, PureValue>::new(2.0)).cast();
+    assert_eq!(scaled.value, 6.0);
+}
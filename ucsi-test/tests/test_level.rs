@@ -0,0 +1,67 @@
+use ucsi::{
+    core::{
+        units::{
+            associated::logarithmic::{
+                Decibel, DecibelMilliwatt, DecibelSoundPressure, DecibelVolt, DecibelWatt, Neper,
+            },
+            base::PureValue,
+            exported::{power::W, pressure::Pa, voltage::V},
+        },
+        value::Value,
+    },
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs - rhs).abs() < 1e-9
+}
+
+#[test]
+fn test_gain_arithmetic() {
+    let gain = Value::<f64, Decibel>::new(3.0) + Value::new(7.0);
+    assert_eq!(gain.value, 10.0);
+    assert!(approx(gain.power_ratio(), 10.0));
+    assert!(approx(
+        gain.power_ratio(),
+        Value::<f64, Decibel>::new(3.0).power_ratio() * Value::<f64, Decibel>::new(7.0).power_ratio()
+    ));
+    assert!(approx(Value::<f64, Decibel>::from_amplitude_ratio(10.0).value, 20.0));
+    assert!(approx(Value::<f64, Decibel>::from_power_ratio(0.5).value, -3.010_299_956_6));
+
+    let neper: Value<f64, PureValue> = Value::<f64, Neper>::new(1.0).to_metric();
+    let decibel = Value::<f64, Decibel>::from_metric(neper);
+    assert!(approx(decibel.value, 8.685_889_638));
+}
+
+#[test]
+fn test_power_level() {
+    let level: Value<f64, DecibelMilliwatt> = Value::new(30.0);
+    let power: Value<f64, W> = level.to_linear();
+    assert!(approx(power.value, 1.0));
+    let level = Value::<f64, DecibelWatt>::from_linear(power);
+    assert!(approx(level.value, 0.0));
+
+    let output = Value::<f64, DecibelMilliwatt>::new(-50.0)
+        .with_gain(Value::new(20.0))
+        .with_gain(Value::new(-3.0));
+    assert_eq!(output.value, -33.0);
+    let gain = output.gain_over(Value::new(-50.0));
+    assert_eq!(gain.value, 17.0);
+
+    let sum = Value::<f64, DecibelMilliwatt>::new(10.0).power_sum(Value::new(10.0));
+    assert!(approx(sum.value, 13.010_299_956_6));
+}
+
+#[test]
+fn test_root_power_level() {
+    let level = Value::<f64, DecibelVolt>::from_linear(Value::<f64, V>::new(10.0));
+    assert!(approx(level.value, 20.0));
+
+    let spl: Value<f64, DecibelSoundPressure> = Value::new(94.0);
+    let pressure: Value<f64, Pa> = spl.to_linear();
+    assert!((pressure.value - 1.0).abs() < 3e-3);
+    let spl = Value::<f64, DecibelSoundPressure>::from_linear(Value::new(2e-5));
+    assert!(approx(spl.value, 0.0));
+
+    let sum = Value::<f64, DecibelSoundPressure>::new(60.0).power_sum(Value::new(60.0));
+    assert!(approx(sum.value, 63.010_299_956_6));
+}
@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+
+use ucsi::{
+    core::{
+        units::{
+            any::SiOpsUnit,
+            associated::{
+                information::{Bit, Kibibyte, Kilobyte},
+                time::{Day, Hour, Minute},
+                volume::Litre,
+                weight::{Gram, Tonne},
+            },
+            base::{kg, m, s},
+            scale::{RescaleFrom, Scale, ScaledUnit},
+        },
+        value::Value,
+    },
+    ops::Scaled,
+    unit, SoftF64,
+};
+
+type Km = Scaled<m, 1000, 1>;
+
+#[test]
+fn test_associated_factors() {
+    assert_eq!(<Gram as ScaledUnit>::FACTOR, Scale::new(1, 1000));
+    assert_eq!(<Kibibyte as ScaledUnit>::FACTOR, Scale::new(8192, 1));
+    assert_eq!(<Km as ScaledUnit>::FACTOR, <Km as SiOpsUnit>::SCALE);
+    assert_eq!(<Tonne as RescaleFrom<Gram>>::FACTOR, Scale::new(1, 1_000_000));
+    assert_eq!(<Minute as RescaleFrom<Day>>::FACTOR, Scale::new(1440, 1));
+}
+
+#[test]
+fn test_rescale_associated() {
+    let mass: Value<f64, kg> = Value::<f64, Gram>::new(1500.0).rescale();
+    assert!((mass.value - 1.5).abs() < 1e-12);
+    let bytes: Value<u64, Kilobyte> = Value::<u64, Kibibyte>::new(1000).rescale();
+    assert_eq!(bytes.value, 1024);
+    let volume: Value<f64, unit!(Km ** { 3 })> = Value::<f64, Litre>::new(1e12).rescale();
+    assert!((volume.value - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_mixed_add_sub() {
+    let mass = Value::<f64, kg>::new(2.0).rescale_add(Value::<f64, Gram>::new(500.0));
+    assert!((mass.value - 2.5).abs() < 1e-12);
+    let mass = Value::<f64, Gram>::new(500.0).rescale_add(Value::<f64, kg>::new(2.0));
+    assert!((mass.value - 2500.0).abs() < 1e-9);
+
+    let time = Value::<i64, Hour>::new(3).rescale_sub(Value::<i64, Minute>::new(59));
+    assert_eq!(time.value, 3);
+    let time = Value::<i64, Minute>::new(10).rescale_add(Value::<i64, Hour>::new(2));
+    assert_eq!(time.value, 130);
+
+    let distance = Value::<f64, Km>::new(1.0).rescale_add(Value::<f64, m>::new(250.0));
+    assert!((distance.value - 1.25).abs() < 1e-12);
+    let information = Value::<u64, Bit>::new(1).rescale_add(Value::<u64, Kilobyte>::new(1));
+    assert_eq!(information.value, 8001);
+}
+
+#[test]
+fn test_mixed_const() {
+    const TIME: Value<u32, s> = Value::<u32, s>::new(30).rescale_add(Value::<u32, Minute>::new(2));
+    assert_eq!(TIME.value, 150);
+    const MASS: Value<SoftF64, kg> =
+        Value::<SoftF64, kg>::new(SoftF64(1.0)).rescale_sub(Value::<SoftF64, Gram>::new(SoftF64(250.0)));
+    assert_eq!(MASS.value.to_f64(), 0.75);
+}
+
+#[test]
+fn test_mixed_cmp() {
+    let hour: Value<f64, Hour> = Value::new(1.0);
+    assert!(hour.rescale_eq(Value::<f64, Minute>::new(60.0)));
+    assert_eq!(
+        hour.rescale_partial_cmp(Value::<f64, s>::new(3601.0)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        Value::<u32, Tonne>::new(1).rescale_partial_cmp(Value::<u32, kg>::new(999)),
+        Some(Ordering::Greater)
+    );
+}
@@ -0,0 +1,72 @@
+use ucsi::{
+    core::{
+        ops::Scaled,
+        units::{
+            associated::weight::Gram,
+            base::{kg, m, s},
+            canonical::Canonical,
+            exported::force::Newton,
+            quantity::{Acceleration, Force, Length, Mass, Pressure, Time, Velocity},
+        },
+        value::Value,
+    },
+    unit, val,
+};
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs - rhs).abs() <= 1e-12 * rhs.abs().max(1.0)
+}
+
+fn speed<L: Length, T: Time>(d: Value<f64, L>, t: Value<f64, T>) -> Value<f64, unit!(m / s)> {
+    let d: Value<f64, m> = d.rescale();
+    let t: Value<f64, s> = t.rescale();
+    (d / t).cast_dim()
+}
+
+fn weight<M: Mass, A: Acceleration>(mass: Value<f64, M>, g: Value<f64, A>) -> Value<f64, Newton> {
+    let mass: Value<f64, kg> = mass.rescale();
+    let g: Value<f64, unit!(m / (s ** { 2 }))> = g.rescale();
+    (mass * g).cast_dim()
+}
+
+type Pascal = Canonical<unit!(kg / (m * (s ** { 2 })))>;
+
+fn pressure<F: Force, L: Length>(force: Value<f64, F>, side: Value<f64, L>) -> Value<f64, Pascal> {
+    let force: Value<f64, Newton> = force.rescale();
+    let side: Value<f64, m> = side.rescale();
+    (force / (side * side)).cast_dim()
+}
+
+fn is_pressure<P: Pressure>(_: &Value<f64, P>) -> bool {
+    true
+}
+
+fn is_velocity<V: Velocity>(_: &Value<f64, V>) -> bool {
+    true
+}
+
+#[test]
+fn test_quantity_bounds() {
+    assert!(approx(speed(val!(10.0 * m), val!(2.0 * s)).value, 5.0));
+    assert!(is_velocity(&val!(1.0 * (m * (s ** { -1 })))));
+
+    let g = val!(9.8 * (m / (s ** { 2 })));
+    let w = weight(val!(2.0 * kg), g);
+    assert!(approx(w.value, 19.6));
+
+    let p = pressure(w, val!(2.0 * m));
+    assert!(is_pressure(&p));
+    assert!(approx(p.value, 4.9));
+}
+
+#[test]
+fn test_quantity_scaled_units() {
+    type Km = Scaled<m, 1000, 1>;
+    type Hour = Scaled<s, 3600, 1>;
+
+    assert!(approx(weight(Value::<f64, Gram>::new(2000.0), val!(9.8 * (m / (s ** { 2 })))).value, 19.6));
+    // `Scaled` units are not canonical, so they are rescaled to the coherent unit first
+    let d: Value<f64, m> = Value::<f64, Km>::new(72.0).rescale();
+    let t: Value<f64, s> = Value::<f64, Hour>::new(1.0).rescale();
+    assert!(approx(speed(d, t).value, 20.0));
+}
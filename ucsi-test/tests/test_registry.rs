@@ -0,0 +1,117 @@
+use ucsi::{
+    core::units::{
+        any::SiOpsUnit,
+        associated::{imperial::Mile, time::Hour},
+        base::{BaseUnitMap, Kilogram, Meter, Second},
+        exported::energy::Joule,
+        registry::{find_by_symbol, Registry, RegistryError, UnitEntry, BUILTIN_UNITS},
+        scale::Scale,
+    },
+    macros::unit_def::si_base_unit_def,
+    ops::Scaled,
+    unit,
+};
+
+si_base_unit_def! {
+    alias pub mod aliases;
+
+    unit Pixel {
+        full_name: "Pixel",
+        short_name: "pixel",
+        unit_symbol: "px",
+    };
+}
+
+#[test]
+fn test_builtin_unique_symbols() {
+    for (i, entry) in BUILTIN_UNITS.iter().enumerate() {
+        assert!(
+            BUILTIN_UNITS[i + 1..].iter().all(|e| e.unit_symbol != entry.unit_symbol),
+            "duplicated symbol `{}`",
+            entry.unit_symbol
+        );
+    }
+}
+
+#[test]
+fn test_lookup() {
+    let registry = Registry::builtin();
+
+    let mile = registry.by_symbol("mi").unwrap();
+    assert_eq!(mile, &UnitEntry::exact::<Mile>());
+    assert_eq!(mile.scale, Some(Scale::new(1_609_344, 1_000)));
+    assert!(mile.is_compatible(&Meter::UNIT_MAP));
+
+    assert_eq!(registry.by_name("Kilowatt hour").unwrap().unit_symbol, "kWh");
+    assert_eq!(registry.by_name("KILOGRAM").unwrap().unit_symbol, "kg");
+    assert_eq!(registry.lookup("hour").unwrap(), &UnitEntry::exact::<Hour>());
+    assert!(registry.lookup("furlong").is_none());
+    assert_eq!(find_by_symbol(BUILTIN_UNITS, "J").unwrap().full_name, "Joule");
+    // prefixes are resolved by `convert::parse_unit` only
+    assert!(find_by_symbol(BUILTIN_UNITS, "km").is_none());
+
+    let degree = registry.by_symbol("°").unwrap();
+    assert_eq!(degree.scale, None);
+    assert!((degree.factor - core::f64::consts::PI / 180.0).abs() < 1e-15);
+}
+
+#[test]
+fn test_compatible() {
+    let registry = Registry::builtin();
+    let energies: Vec<_> = registry
+        .compatible(&Joule::UNIT_MAP)
+        .map(|e| e.unit_symbol)
+        .collect();
+    for symbol in ["J", "N m", "eV", "cal", "kcal", "kWh", "erg", "BTU"] {
+        assert!(energies.contains(&symbol), "missing `{}`", symbol);
+    }
+
+    let speed = <unit!(Meter / Second) as SiOpsUnit>::UNIT_MAP;
+    let speeds: Vec<_> = registry.compatible(&speed).map(|e| e.unit_symbol).collect();
+    assert_eq!(speeds, ["kn"]);
+    let masses = registry.compatible(&Kilogram::UNIT_MAP).count();
+    assert!(masses >= 8);
+}
+
+#[test]
+fn test_conversion_metadata() {
+    let registry = Registry::builtin();
+    let fahrenheit = registry.by_symbol("°F").unwrap();
+    assert!(!fahrenheit.is_linear());
+    assert!((fahrenheit.to_coherent(32.0) - 273.15).abs() < 1e-12);
+    assert!((fahrenheit.from_coherent(373.15) - 212.0).abs() < 1e-12);
+
+    let erg = registry.by_symbol("erg").unwrap();
+    assert!(erg.is_compatible(&Joule::UNIT_MAP));
+    assert_eq!(erg.scale, Some(Scale::new(1, 10_000_000)));
+
+    let kilometer = UnitEntry {
+        full_name: "Kilometer",
+        short_name: "kilometer",
+        unit_symbol: "km",
+        scale: Some(<Scaled<Meter, 1000, 1> as SiOpsUnit>::SCALE),
+        factor: 1000.0,
+        ..UnitEntry::defined::<Meter>()
+    };
+    assert_eq!(kilometer.to_coherent(2.0), 2000.0);
+    assert_eq!(kilometer.from_coherent(500.0), 0.5);
+}
+
+#[test]
+fn test_register() {
+    let mut registry = Registry::new();
+    assert!(registry.entries().is_empty());
+    registry.register(UnitEntry::defined::<Pixel>()).unwrap();
+    assert_eq!(
+        registry.register(UnitEntry::defined::<Pixel>()),
+        Err(RegistryError::DuplicateSymbol("px"))
+    );
+
+    let mut registry = Registry::builtin();
+    let count = registry.entries().len();
+    registry
+        .register_all([UnitEntry::defined::<Pixel>(), UnitEntry::defined::<Meter>()])
+        .unwrap_err();
+    assert_eq!(registry.entries().len(), count + 1);
+    assert!(!registry.by_name("pixel").unwrap().is_compatible(&BaseUnitMap::EMPTY));
+}
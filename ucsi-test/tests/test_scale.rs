@@ -0,0 +1,109 @@
+use datastruct::DataStruct;
+use ucsi::{
+    core::{
+        format::ufmt::{SiDefault, SiDefaultOption},
+        units::{
+            any::{is_same_scale, is_same_type, SiOpsUnit},
+            base::{m, s},
+            exported::energy::J,
+            scale::Scale,
+        },
+        value::Value,
+    },
+    ops::Scaled,
+    unit, SoftF64,
+};
+
+type Km = Scaled<m, 1000, 1>;
+type Mm = Scaled<m, 1, 1000>;
+type Hour = Scaled<s, 3600, 1>;
+type KmPerHour = unit!(Km / Hour);
+type Kj = Scaled<J, 1000, 1>;
+
+#[test]
+fn test_scale_arithmetic() {
+    assert_eq!(Scale::new(6, 4), Scale::new(3, 2));
+    assert_eq!(Scale::new(-2, 4).numerator(), -1);
+    assert_eq!(Scale::new(1000, 1).mul(Scale::new(1, 1000)), Scale::ONE);
+    assert_eq!(Scale::new(10, 1).powi(-2), Scale::new(1, 100));
+    assert_eq!(Scale::new(4, 1).powf(4, 2), Scale::new(16, 1));
+    assert_eq!(Scale::new(3, 8).to_string(), "3/8");
+}
+
+#[test]
+fn test_scale_powi() {
+    assert_eq!(Scale::new(2, 3).powi(5), Scale::new(32, 243));
+    assert_eq!(Scale::new(-2, 1).powi(-3), Scale::new(-1, 8));
+    assert_eq!(Scale::new(7, 1).powi(0), Scale::ONE);
+    assert_eq!(Scale::ONE.powi(i32::MIN), Scale::ONE);
+    assert_eq!(Scale::new(10, 1).checked_powi(38).map(|s| s.numerator()), Some(10i128.pow(38)));
+    assert_eq!(Scale::new(10, 1).checked_powi(39), None);
+    assert_eq!(Scale::new(2, 1).checked_powi(999_999_999), None);
+    assert_eq!(Scale::new(i64::MAX, 1).checked_mul(Scale::new(i64::MAX, 1).powi(2)), None);
+}
+
+#[test]
+#[should_panic(expected = "scale factor overflow")]
+fn test_scale_powi_overflow() {
+    let _ = Scale::new(1000, 1).powi(100_000);
+}
+
+#[test]
+#[should_panic(expected = "integer overflow in unit conversion")]
+fn test_rescale_overflow() {
+    let _ = Value::<u8, Km>::new(1).rescale::<m>();
+}
+
+#[test]
+fn test_scale_composition() {
+    assert!(is_same_type::<Km, m>());
+    assert!(!is_same_scale::<Km, m>());
+    assert_eq!(<KmPerHour as SiOpsUnit>::SCALE, Scale::new(5, 18));
+    assert_eq!(<unit!(Km ** { 2 }) as SiOpsUnit>::SCALE, Scale::new(1_000_000, 1));
+    assert_eq!(<unit!(Km * Mm) as SiOpsUnit>::SCALE, Scale::ONE);
+    assert_eq!(<Scaled<Km, 1, 1000> as SiOpsUnit>::SCALE, Scale::ONE);
+    assert_eq!(<Kj as SiOpsUnit>::KIND, <J as SiOpsUnit>::KIND);
+}
+
+#[test]
+fn test_rescale() {
+    let speed: Value<f64, KmPerHour> = Value::new(90.0);
+    let speed: Value<f64, unit!(m / s)> = speed.rescale();
+    assert!((speed.value - 25.0).abs() < 1e-12);
+
+    let distance: Value<i64, Mm> = Value::<i64, Km>::new(3).rescale();
+    assert_eq!(distance.value, 3_000_000);
+    let distance: Value<i64, Km> = Value::<i64, Mm>::new(2_999_999).rescale();
+    assert_eq!(distance.value, 2);
+
+    let energy: Value<f64, Kj> = Value::<f64, J>::new(1500.0).rescale();
+    assert!((energy.value - 1.5).abs() < 1e-12);
+
+    let product = Value::<f64, Km>::new(2.0) * Value::<f64, Km>::new(3.0);
+    let area: Value<f64, unit!(m ** { 2 })> = product.rescale();
+    assert!((area.value - 6e6).abs() < 1e-6);
+}
+
+#[test]
+fn test_rescale_const() {
+    const LENGTH: Value<u32, m> = Value::<u32, Km>::new(42).rescale();
+    assert_eq!(LENGTH.value, 42_000);
+    const TIME: Value<SoftF64, s> = Value::<SoftF64, Hour>::new(SoftF64(0.5)).rescale();
+    assert_eq!(TIME.value.to_f64(), 1800.0);
+}
+
+#[test]
+fn test_scale_equality() {
+    let km: Value<f64, Km> = Value::new(1.0);
+    let m: Value<f64, m> = Value::new(1.0);
+    assert!(!km.is_equal(&m));
+    assert!(km.partial_cmp_unit(&m).is_none());
+    assert!(km.is_equal(&Value::<f64, Scaled<Mm, 1_000_000, 1>>::new(1.0)));
+}
+
+#[test]
+fn test_scale_format() {
+    let speed: Value<f64, KmPerHour> = Value::new(1.0);
+    let unit = speed.fmt_unit::<SiDefault>(SiDefaultOption::data_default());
+    assert_eq!(unit, "5/18 * m + s^(-1)");
+}
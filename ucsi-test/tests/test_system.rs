@@ -0,0 +1,87 @@
+use datastruct::DataStruct;
+use ucsi::{
+    core::{
+        format::ufmt::{SiDefault, SiDefaultOption},
+        ops::Scaled,
+        units::{
+            any::is_same_type,
+            base::{kg, m, s},
+            exported::{energy::Joule, force::Newton},
+            system::{
+                cgs::{self, Barye, Centimeter, Cgs, Dyne, Erg, Gram},
+                gaussian::{Gaussian, Statampere, Statcoulomb},
+                natural::{ElectronVolt, Natural},
+            },
+        },
+        value::Value,
+    },
+    unit, val,
+};
+
+type Pascal = unit!(kg / (m * (s ** { 2 })));
+type CgsSecond = cgs::Second;
+type InverseEv = unit!(ElectronVolt ** { -1 });
+
+fn approx(lhs: f64, rhs: f64) -> bool {
+    (lhs / rhs - 1.0).abs() < 1e-9
+}
+
+#[test]
+fn test_system_dimension() {
+    assert!(!is_same_type::<Centimeter, m>());
+    assert!(!is_same_type::<cgs::Second, s>());
+    assert!(!is_same_type::<Erg, Joule>());
+    assert!(is_same_type::<Dyne, unit!((Gram * Centimeter) / (CgsSecond ** { 2 }))>());
+    assert!(is_same_type::<Statampere, unit!(Statcoulomb / CgsSecond)>());
+}
+
+#[test]
+fn test_cgs_format() {
+    let speed = val!(1.0 * Centimeter) / val!(1.0 * CgsSecond);
+    let unit = speed.fmt_unit::<SiDefault>(SiDefaultOption::data_default());
+    assert_eq!(unit, "cm_cgs + s_cgs^(-1)");
+}
+
+#[test]
+fn test_cgs_to_si() {
+    let force: Value<f64, Newton> = val!(1.0e5 * Dyne).convert_system::<Cgs, _>();
+    assert!(approx(force.value, 1.0));
+    let energy: Value<f64, Erg> = val!(2.0 * Joule).convert_system::<Cgs, _>();
+    assert!(approx(energy.value, 2.0e7));
+    let pressure: Value<f64, Pascal> = val!(10.0 * Barye).convert_system_const::<Cgs, _>();
+    assert!(approx(pressure.value, 1.0));
+    let length: Value<f64, m> = val!(250.0 * Centimeter).convert_system::<Cgs, _>();
+    assert!(approx(length.value, 2.5));
+
+    // Gaussian shares the mechanical units with CGS
+    let energy: Value<f64, Joule> = val!(1.0 * Erg).convert_system::<Gaussian, _>();
+    assert!(approx(energy.value, 1.0e-7));
+}
+
+#[test]
+fn test_natural_to_si() {
+    let mass: Value<f64, ElectronVolt> = val!(1.0 * kg).convert_system::<Natural, _>();
+    assert!(approx(mass.value, 5.609_588_603_804_452e35));
+    let time: Value<f64, InverseEv> = val!(1.0 * s).convert_system::<Natural, _>();
+    assert!(approx(time.value, 1.602_176_634e-19 / 1.054_571_817e-34));
+
+    let energy: Value<f64, Joule> = val!(1.0 * ElectronVolt).convert_system::<Natural, _>();
+    assert!(approx(energy.value, 1.602_176_634e-19));
+    let back: Value<f64, ElectronVolt> = energy.convert_system::<Natural, _>();
+    assert!(approx(back.value, 1.0));
+}
+
+#[test]
+fn test_system_scaled_units() {
+    type Km = Scaled<m, 1000, 1>;
+    type Mm = Scaled<Centimeter, 1, 10>;
+    type GeV = Scaled<ElectronVolt, 1_000_000_000, 1>;
+
+    let length: Value<f64, Centimeter> = Value::<f64, Km>::new(1.0).convert_system::<Cgs, _>();
+    assert!(approx(length.value, 1.0e5));
+    let length: Value<f64, Km> = Value::<f64, Mm>::new(2.0e6).convert_system_const::<Cgs, _>();
+    assert!(approx(length.value, 2.0));
+
+    let energy: Value<f64, Joule> = Value::<f64, GeV>::new(1.0).convert_system::<Natural, _>();
+    assert!(approx(energy.value, 1.602_176_634e-10));
+}
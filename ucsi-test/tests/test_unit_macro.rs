@@ -0,0 +1,69 @@
+use std::any::TypeId;
+
+use ucsi::{
+    ops::{self, Div, Mul, PowFrac, PowI, Scaled},
+    units::base::{kg, m, s, PureValue},
+    Value,
+};
+
+fn same<T: 'static, U: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<U>()
+}
+
+#[test]
+fn test_precedence() {
+    assert!(same::<ops::unit!(kg * m / s^2), ucsi::unit!((kg * m) / (s ** { 2 }))>());
+    assert!(same::<ops::unit!(kg * m / s^2), Div<Mul<kg, m>, PowI<s, 2>>>());
+    assert!(same::<ops::unit!(m / s / s), Div<Div<m, s>, s>>());
+    assert!(same::<ops::unit!(m / (s * s)), Div<m, Mul<s, s>>>());
+    assert!(same::<ops::unit!(kg), kg>());
+    assert!(same::<ops::unit!(ucsi::units::base::Meter), m>());
+}
+
+#[test]
+fn test_powers() {
+    assert!(same::<ops::unit!(m^-1), PowI<m, -1>>());
+    assert!(same::<ops::unit!(m^(-3)), PowI<m, -3>>());
+    assert!(same::<ops::unit!(s^(1/2)), PowFrac<s, 1, 2>>());
+    assert!(same::<ops::unit!(s^(-1/2)), PowFrac<s, -1, 2>>());
+    assert!(same::<ops::unit!(s ** { 1 / 2 }), ucsi::unit!(s ** { 1 / 2 })>());
+    assert!(same::<ops::unit!(m * s^2 * kg), Mul<Mul<m, PowI<s, 2>>, kg>>());
+    assert!(same::<ops::unit!(1 / s), Div<PureValue, s>>());
+    assert!(same::<ops::unit!(Scaled<m, 1000, 1> / s), Div<Scaled<m, 1000, 1>, s>>());
+    assert!(same::<ops::unit!(Scaled<ucsi::units::base::m, 1000, 1>), Scaled<m, 1000, 1>>());
+    assert!(same::<
+        ops::unit!(Scaled<Scaled<::ucsi::units::base::s, 60, 1>, 60, 1> ^ -1),
+        PowI<Scaled<Scaled<s, 60, 1>, 60, 1>, -1>,
+    >());
+}
+
+#[test]
+fn test_string_input() {
+    assert!(same::<ops::unit!("kg·m/s²"), Div<Mul<kg, m>, PowI<s, 2>>>());
+    assert!(same::<ops::unit!("m⁻¹"), PowI<m, -1>>());
+    assert!(same::<ops::unit!("s¹ᐟ²"), PowFrac<s, 1, 2>>());
+    assert!(same::<ops::unit!("kg × m^-2"), Mul<kg, PowI<m, -2>>>());
+    assert!(same::<ops::unit!("1/s"), Div<PureValue, s>>());
+}
+
+#[test]
+fn test_string_escapes() {
+    assert!(same::<ops::unit!(r"kg·m/s²"), Div<Mul<kg, m>, PowI<s, 2>>>());
+    assert!(same::<ops::unit!(r#"m / s"#), Div<m, s>>());
+    assert!(same::<ops::unit!("kg\u{b7}m\t/\x20s\u{00B2}"), Div<Mul<kg, m>, PowI<s, 2>>>());
+    assert!(same::<
+        ops::unit!("kg * \
+                    m"),
+        Mul<kg, m>,
+    >());
+}
+
+type Work = ops::unit!("kg·m²/s²");
+
+#[test]
+fn test_value() {
+    let force: Value<f64, ops::unit!(kg * m / s^2)> = Value::new(2.0);
+    let work = force * Value::<f64, m>::new(3.0);
+    let work: Value<f64, Work> = work.cast_const();
+    assert_eq!(work.value, 6.0);
+}
@@ -0,0 +1,476 @@
+//! # The `catalogue` module
+//!
+//! Generate associated units from declarative catalogue files.
+//!
+//! Every `<module>.toml` file of a catalogue directory becomes the module `<module>`,
+//! with the unit definitions, the aliases, the factors and the registry entries of its units.
+//! The catalogue of this library lives in `ucsi/catalogue/`,
+//! and its generated modules are part of [`units::associated`](crate::units::associated).
+//!
+//! ## Format
+//!
+//! The files are parsed with the `toml` crate, and contain `[[unit]]` tables:
+//!
+//! ```toml
+//! doc = "Module documentation."
+//! # optional, `ucsi::core::units::base::*` is always imported
+//! imports = ["ucsi::core::units::exported::pressure::Pascal"]
+//!
+//! [[unit]]
+//! name = "Knot"                  # type name
+//! full_name = "Knot"
+//! short_name = "knot"            # optional, the lowercase full name by default
+//! symbol = "kn"
+//! aliases = ["kn", "kt"]         # optional, aliases must be identifiers
+//! base = "Meter / Second"        # `unit!` syntax
+//! factor = "1852 / 3600"         # `1 unit = factor base unit`
+//! offset = 0                     # optional, `x unit = (x * factor + offset) base unit`
+//! doc = "Knot, one nautical mile per hour."  # optional
+//! ```
+//!
+//! An integer or a fraction of integers is an exact factor,
+//! which generates a [`ScaledUnit`](crate::core::units::scale::ScaledUnit) implementation.
+//! Any other factor is a rust expression of type `f64`,
+//! which generates a [`LinearUnit`](crate::core::units::scale::LinearUnit) implementation.
+//! Units with an offset, like the degree Celsius, have neither and only convert through the registry.
+//!
+//! ## Usage
+//!
+//! Add the library as a build dependency with the `catalogue` feature,
+//! and generate the catalogue in the build script:
+//!
+//! ```toml
+//! [dependencies]
+//! ucsi = { version = "0.1", features = ["associated_types"] }
+//!
+//! [build-dependencies]
+//! ucsi = { version = "0.1", default-features = false, features = ["catalogue"] }
+//! ```
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use std::{env, path::PathBuf};
+//!
+//! use ucsi::catalogue::Catalogue;
+//!
+//! fn main() {
+//!     let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("catalogue.rs");
+//!     Catalogue::new("catalogue").generate(out);
+//! }
+//! ```
+//!
+//! Then include the generated modules, e.g. `catalogue/marine.toml` becomes `units::marine`:
+//!
+//! ```rust,ignore
+//! pub mod units {
+//!     include!(concat!(env!("OUT_DIR"), "/catalogue.rs"));
+//! }
+//!
+//! let mut registry = Registry::builtin();
+//! registry.register_all(units::CATALOGUE_UNITS.iter().flat_map(|units| units.iter().copied()))?;
+//! ```
+//!
+//! Each module contains the unit types, an `aliases` module and the registry entries `UNITS`.
+//! The generated code refers to the library as `::ucsi`,
+//! use [`Catalogue::crate_path`] if the dependency is renamed.
+//!
+//! Units of downstream crates cannot have inherent methods on [`Value`](crate::Value),
+//! so they convert with `rescale` and the registry instead of `to_metric` and `from_metric`.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+fn get_str(table: &Table, key: &str) -> Result<Option<String>, String> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("`{}` must be a string", key)),
+    }
+}
+
+fn require_str(table: &Table, key: &str) -> Result<String, String> {
+    get_str(table, key)?.ok_or_else(|| format!("missing `{}`", key))
+}
+
+fn get_str_array(table: &Table, key: &str) -> Result<Vec<String>, String> {
+    match table.get(key) {
+        None => Ok(Vec::new()),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| match item {
+                Value::String(s) => Ok(s.clone()),
+                _ => Err(format!("`{}` must be an array of strings", key)),
+            })
+            .collect(),
+        Some(_) => Err(format!("`{}` must be an array of strings", key)),
+    }
+}
+
+/// A number or an expression, as rust source.
+fn get_expr(table: &Table, key: &str) -> Result<Option<String>, String> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+        Some(Value::Integer(n)) => Ok(Some(n.to_string())),
+        Some(Value::Float(n)) if n.is_finite() => Ok(Some(format!("{:?}", n))),
+        Some(_) => Err(format!("`{}` must be a finite number or a string", key)),
+    }
+}
+
+fn is_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_integer(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit() || c == '_') && s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+enum Factor {
+    Exact(String, String),
+    Float(String),
+}
+
+impl Factor {
+    fn parse(src: &str) -> Factor {
+        if is_integer(src) {
+            return Factor::Exact(src.to_string(), "1".to_string());
+        }
+        match src.split_once('/') {
+            Some((num, den)) if is_integer(num.trim()) && is_integer(den.trim()) => {
+                Factor::Exact(num.trim().to_string(), den.trim().to_string())
+            }
+            _ => Factor::Float(src.to_string()),
+        }
+    }
+
+    fn float_expr(&self) -> String {
+        match self {
+            Factor::Exact(num, den) if den == "1" => format!("{}.0", num),
+            Factor::Exact(num, den) => format!("{}.0 / {}.0", num, den),
+            Factor::Float(expr) => expr.clone(),
+        }
+    }
+}
+
+struct Unit {
+    name: String,
+    full_name: String,
+    short_name: String,
+    symbol: String,
+    aliases: Vec<String>,
+    base: String,
+    factor: Factor,
+    offset: Option<String>,
+    doc: Option<String>,
+}
+
+impl Unit {
+    fn from_table(table: &Table) -> Result<Unit, String> {
+        for (key, _) in table {
+            if !matches!(
+                key.as_str(),
+                "name" | "full_name" | "short_name" | "symbol" | "aliases" | "base" | "factor" | "offset" | "doc"
+            ) {
+                return Err(format!("unknown key `{}`", key));
+            }
+        }
+
+        let name = require_str(table, "name")?;
+        if !is_ident(&name) {
+            return Err(format!("`{}` is not a valid type name", name));
+        }
+        let aliases = get_str_array(table, "aliases")?;
+        if let Some(alias) = aliases.iter().find(|a| !is_ident(a)) {
+            return Err(format!("alias `{}` is not an identifier", alias));
+        }
+        let full_name = require_str(table, "full_name")?;
+        let offset = match table.get("offset") {
+            Some(Value::Integer(n)) => Some(format!("{:?}", *n as f64)),
+            _ => get_expr(table, "offset")?,
+        }
+        .filter(|o| o.parse::<f64>() != Ok(0.0));
+
+        Ok(Unit {
+            short_name: get_str(table, "short_name")?.unwrap_or_else(|| full_name.to_lowercase()),
+            full_name,
+            symbol: require_str(table, "symbol")?,
+            aliases,
+            base: require_str(table, "base")?,
+            factor: Factor::parse(&get_expr(table, "factor")?.ok_or("missing `factor`")?),
+            offset,
+            doc: get_str(table, "doc")?,
+            name,
+        })
+    }
+
+    fn const_prefix(&self) -> String {
+        let mut out = String::new();
+        for (i, c) in self.name.chars().enumerate() {
+            if c.is_ascii_uppercase() && i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_uppercase());
+        }
+        out
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, doc: &str) {
+    for line in doc.lines() {
+        writeln!(out, "{}#[doc = {:?}]", indent, format!(" {}", line)).unwrap();
+    }
+}
+
+/// A directory of catalogue files.
+///
+/// See the [module documentation](self) for the file format.
+#[derive(Debug, Clone)]
+pub struct Catalogue {
+    dir: PathBuf,
+    crate_path: String,
+    builtin: bool,
+}
+
+impl Catalogue {
+    /// Read the catalogue files of `dir`.
+    pub fn new(dir: impl AsRef<Path>) -> Catalogue {
+        Catalogue {
+            dir: dir.as_ref().to_path_buf(),
+            crate_path: "::ucsi".to_string(),
+            builtin: false,
+        }
+    }
+
+    /// The catalogue of this library, generated into `core::units::associated`.
+    ///
+    /// Its units have `to_metric` and `from_metric` methods,
+    /// which can only be implemented inside this library.
+    #[doc(hidden)]
+    pub fn builtin(dir: impl AsRef<Path>) -> Catalogue {
+        Catalogue {
+            dir: dir.as_ref().to_path_buf(),
+            crate_path: "crate".to_string(),
+            builtin: true,
+        }
+    }
+
+    /// Set the path of this library in the generated code, `::ucsi` by default.
+    pub fn crate_path(mut self, path: impl Into<String>) -> Catalogue {
+        self.crate_path = path.into();
+        self
+    }
+
+    fn files(&self) -> Result<Vec<PathBuf>, String> {
+        let mut files = fs::read_dir(&self.dir)
+            .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>())
+            .map_err(|e| format!("cannot read `{}`: {}", self.dir.display(), e))?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect::<Vec<_>>();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Generate the source of all catalogue modules.
+    ///
+    /// Errors are prefixed with the path of the file.
+    pub fn to_source(&self) -> Result<String, String> {
+        let mut code = String::new();
+        writeln!(code, "// @generated by `ucsi::catalogue`, do not edit.").unwrap();
+        writeln!(code).unwrap();
+
+        let mut modules = Vec::new();
+        for path in self.files()? {
+            let source = path.file_name().unwrap().to_string_lossy().to_string();
+            let module = path.file_stem().unwrap().to_string_lossy().to_string();
+            let error = |e: String| format!("{}: {}", path.display(), e);
+            if !is_ident(&module) {
+                return Err(error(format!("`{}` is not a valid module name", module)));
+            }
+
+            let text = fs::read_to_string(&path).map_err(|e| error(e.to_string()))?;
+            let doc = text.parse::<Table>().map_err(|e| error(e.to_string()))?;
+            self.generate_module(&mut code, &module, &source, &doc).map_err(error)?;
+            modules.push(module);
+        }
+
+        let krate = &self.crate_path;
+        if self.builtin {
+            writeln!(code, "mod __collect_catalogue {{").unwrap();
+            for module in &modules {
+                writeln!(code, "    pub use super::{}::impl_aliases::*;", module).unwrap();
+            }
+            writeln!(code, "}}").unwrap();
+            writeln!(code).unwrap();
+        }
+        writeln!(code, "/// Registry entries of all catalogue modules.").unwrap();
+        writeln!(
+            code,
+            "{} const CATALOGUE_UNITS: &[&[{}::core::units::registry::UnitEntry]] = &[",
+            if self.builtin { "pub(crate)" } else { "pub" },
+            krate
+        )
+        .unwrap();
+        for module in &modules {
+            writeln!(code, "    {}::UNITS,", module).unwrap();
+        }
+        writeln!(code, "];").unwrap();
+        Ok(code)
+    }
+
+    /// Generate all catalogue modules into the file `out`, for build scripts.
+    ///
+    /// The build script is rerun when the catalogue changes.
+    ///
+    /// Panics if a catalogue file is invalid, or `out` cannot be written.
+    pub fn generate(&self, out: impl AsRef<Path>) {
+        let out = out.as_ref();
+        println!("cargo:rerun-if-changed={}", self.dir.display());
+        for path in self.files().unwrap_or_else(|e| panic!("{}", e)) {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let code = self.to_source().unwrap_or_else(|e| panic!("{}", e));
+        fs::write(out, code).unwrap_or_else(|e| panic!("cannot write `{}`: {}", out.display(), e));
+    }
+
+    fn generate_module(&self, out: &mut String, module: &str, source: &str, doc: &Table) -> Result<(), String> {
+        for key in doc.keys() {
+            if !matches!(key.as_str(), "doc" | "imports" | "unit") {
+                return Err(format!("unknown key `{}`", key));
+            }
+        }
+        let units = match doc.get("unit") {
+            Some(Value::Array(units)) => units
+                .iter()
+                .enumerate()
+                .map(|(i, unit)| match unit {
+                    Value::Table(table) => Unit::from_table(table),
+                    _ => Err("`unit` must be an array of tables".to_string()),
+                }
+                .map_err(|e| format!("unit #{}: {}", i + 1, e)))
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => return Err("`unit` must be an array of tables".to_string()),
+            None => Vec::new(),
+        };
+        if units.is_empty() {
+            return Err("no `[[unit]]` entries".to_string());
+        }
+
+        let krate = &self.crate_path;
+        writeln!(out, "// Generated from `{}`.", source).unwrap();
+        if let Some(text) = get_str(doc, "doc")? {
+            write_doc(out, "", &text);
+        }
+        writeln!(out, "pub mod {} {{", module).unwrap();
+        writeln!(out, "    #[allow(unused_imports)]").unwrap();
+        writeln!(out, "    use {}::core::units::base::*;", krate).unwrap();
+        for import in get_str_array(doc, "imports")? {
+            writeln!(out, "    use {};", import).unwrap();
+        }
+        writeln!(out, "    use {}::core::units::registry::UnitEntry;", krate).unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "    {}::__impl_si_associated_unit_def! {{", krate).unwrap();
+        if self.builtin {
+            writeln!(out, "        alias pub(crate) mod impl_aliases;").unwrap();
+        } else {
+            writeln!(out, "        alias pub mod aliases;").unwrap();
+        }
+        for unit in &units {
+            writeln!(out).unwrap();
+            if let Some(doc) = &unit.doc {
+                write_doc(out, "        ", doc);
+            }
+            writeln!(out, "        unit {}", unit.name).unwrap();
+            writeln!(out, "        based on {}::unit!({}) {{", krate, unit.base).unwrap();
+            writeln!(out, "            full_name: {:?},", unit.full_name).unwrap();
+            writeln!(out, "            short_name: {:?},", unit.short_name).unwrap();
+            writeln!(out, "            unit_symbol: {:?},", unit.symbol).unwrap();
+            writeln!(out, "        }}").unwrap();
+            if unit.aliases.is_empty() {
+                writeln!(out, "        ;").unwrap();
+            } else {
+                writeln!(out, "        alias {};", unit.aliases.join(", ")).unwrap();
+            }
+        }
+        writeln!(out, "    }}").unwrap();
+
+        let mut entries = Vec::new();
+        for unit in &units {
+            writeln!(out).unwrap();
+            match (&unit.factor, &unit.offset) {
+                (Factor::Exact(num, den), None) => {
+                    if self.builtin {
+                        writeln!(out, "    rational_conversion! {{").unwrap();
+                        writeln!(out, "        {}: {} / {};", unit.name, num, den).unwrap();
+                        writeln!(out, "    }}").unwrap();
+                    } else {
+                        writeln!(out, "    {}::__impl_exact_scale! {{", krate).unwrap();
+                        writeln!(out, "        {}: {} / {};", unit.name, num, den).unwrap();
+                        writeln!(out, "    }}").unwrap();
+                        writeln!(out, "    {}::__impl_linear_unit! {{", krate).unwrap();
+                        writeln!(out, "        {}: {};", unit.name, unit.factor.float_expr()).unwrap();
+                        writeln!(out, "    }}").unwrap();
+                    }
+                    entries.push(format!("UnitEntry::exact::<{}>()", unit.name));
+                }
+                (Factor::Float(expr), None) => {
+                    if self.builtin {
+                        writeln!(out, "    float_conversion! {{").unwrap();
+                    } else {
+                        writeln!(out, "    {}::__impl_linear_unit! {{", krate).unwrap();
+                    }
+                    writeln!(out, "        {}: {};", unit.name, expr).unwrap();
+                    writeln!(out, "    }}").unwrap();
+                    entries.push(format!("UnitEntry::linear::<{}>()", unit.name));
+                }
+                (factor, Some(offset)) => {
+                    let prefix = unit.const_prefix();
+                    writeln!(out, "    const {}_FACTOR: f64 = {};", prefix, factor.float_expr()).unwrap();
+                    writeln!(out, "    const {}_OFFSET: f64 = {};", prefix, offset).unwrap();
+                    writeln!(
+                        out,
+                        "    const {prefix}_SCALE: f64 = <<{name} as {krate}::core::units::associated::SiAssociatedUnit>::BaseUnit \
+                         as {krate}::core::units::any::SiOpsUnit>::SCALE.to_f64();",
+                        prefix = prefix,
+                        name = unit.name,
+                        krate = krate,
+                    )
+                    .unwrap();
+                    if self.builtin {
+                        writeln!(out).unwrap();
+                        writeln!(out, "    affine_conversion! {{").unwrap();
+                        writeln!(out, "        {}: {}_FACTOR, {}_OFFSET;", unit.name, prefix, prefix).unwrap();
+                        writeln!(out, "    }}").unwrap();
+                    }
+                    entries.push(format!(
+                        "UnitEntry::affine::<{name}>(\n            \
+                         {prefix}_SCALE * {prefix}_FACTOR,\n            \
+                         {prefix}_SCALE * {prefix}_OFFSET,\n        )",
+                        name = unit.name,
+                        prefix = prefix,
+                    ));
+                }
+            }
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "    /// Registry entries of the units in this module.").unwrap();
+        writeln!(out, "    pub const UNITS: &[UnitEntry] = &[").unwrap();
+        for entry in entries {
+            writeln!(out, "        {},", entry).unwrap();
+        }
+        writeln!(out, "    ];").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        Ok(())
+    }
+}
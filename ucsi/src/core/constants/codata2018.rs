@@ -0,0 +1,38 @@
+//! # CODATA 2018
+//!
+//! The CODATA 2018 recommended values of some measured constants.
+//! Standard uncertainties are in the [`uncertainty`] module.
+
+use crate::{
+    unit,
+    units::{
+        base::{Ampere, Kilogram, Meter, PureValue, Second},
+        exported::force::Newton,
+    },
+};
+
+__impl_constants! {
+    /// Newtonian constant of gravitation, `G`.
+    const G: unit!((Meter ** { 3 }) / (Kilogram * (Second ** { 2 })))
+        = 6.674_30e-11, uncertainty 0.000_15e-11;
+    /// Fine-structure constant, `α`.
+    const ALPHA: PureValue = 7.297_352_569_3e-3, uncertainty 0.000_000_001_1e-3;
+    /// Electron mass, `m_e`.
+    const M_E: Kilogram = 9.109_383_701_5e-31, uncertainty 0.000_000_002_8e-31;
+    /// Proton mass, `m_p`.
+    const M_P: Kilogram = 1.672_621_923_69e-27, uncertainty 0.000_000_000_51e-27;
+    /// Neutron mass, `m_n`.
+    const M_N: Kilogram = 1.674_927_498_04e-27, uncertainty 0.000_000_000_95e-27;
+    /// Atomic mass constant, `m_u`.
+    const M_U: Kilogram = 1.660_539_066_60e-27, uncertainty 0.000_000_000_50e-27;
+    /// Vacuum magnetic permeability, `μ_0`.
+    const MU_0: unit!(Newton / (Ampere ** { 2 }))
+        = 1.256_637_062_12e-6, uncertainty 0.000_000_000_19e-6;
+    /// Vacuum electric permittivity, `ε_0`.
+    const EPSILON_0: unit!(((Second ** { 4 }) * (Ampere ** { 2 })) / (Kilogram * (Meter ** { 3 })))
+        = 8.854_187_812_8e-12, uncertainty 0.000_000_001_3e-12;
+    /// Rydberg constant, `R_∞`.
+    const R_INF: unit!(Meter ** { -1 }) = 10_973_731.568_160, uncertainty 0.000_021;
+    /// Bohr radius, `a_0`.
+    const A_0: Meter = 5.291_772_109_03e-11, uncertainty 0.000_000_000_80e-11;
+}
@@ -0,0 +1,38 @@
+//! # CODATA 2022
+//!
+//! The CODATA 2022 recommended values of some measured constants.
+//! Standard uncertainties are in the [`uncertainty`] module.
+
+use crate::{
+    unit,
+    units::{
+        base::{Ampere, Kilogram, Meter, PureValue, Second},
+        exported::force::Newton,
+    },
+};
+
+__impl_constants! {
+    /// Newtonian constant of gravitation, `G`.
+    const G: unit!((Meter ** { 3 }) / (Kilogram * (Second ** { 2 })))
+        = 6.674_30e-11, uncertainty 0.000_15e-11;
+    /// Fine-structure constant, `α`.
+    const ALPHA: PureValue = 7.297_352_564_3e-3, uncertainty 0.000_000_001_1e-3;
+    /// Electron mass, `m_e`.
+    const M_E: Kilogram = 9.109_383_713_9e-31, uncertainty 0.000_000_002_8e-31;
+    /// Proton mass, `m_p`.
+    const M_P: Kilogram = 1.672_621_925_95e-27, uncertainty 0.000_000_000_52e-27;
+    /// Neutron mass, `m_n`.
+    const M_N: Kilogram = 1.674_927_500_56e-27, uncertainty 0.000_000_000_85e-27;
+    /// Atomic mass constant, `m_u`.
+    const M_U: Kilogram = 1.660_539_068_92e-27, uncertainty 0.000_000_000_52e-27;
+    /// Vacuum magnetic permeability, `μ_0`.
+    const MU_0: unit!(Newton / (Ampere ** { 2 }))
+        = 1.256_637_061_27e-6, uncertainty 0.000_000_000_20e-6;
+    /// Vacuum electric permittivity, `ε_0`.
+    const EPSILON_0: unit!(((Second ** { 4 }) * (Ampere ** { 2 })) / (Kilogram * (Meter ** { 3 })))
+        = 8.854_187_818_8e-12, uncertainty 0.000_000_001_4e-12;
+    /// Rydberg constant, `R_∞`.
+    const R_INF: unit!(Meter ** { -1 }) = 10_973_731.568_157, uncertainty 0.000_012;
+    /// Bohr radius, `a_0`.
+    const A_0: Meter = 5.291_772_105_44e-11, uncertainty 0.000_000_000_82e-11;
+}
@@ -0,0 +1,127 @@
+//! # The `constants` module
+//!
+//! Physical constants typed by unit.
+//!
+//! This module contains the seven SI-defining constants, whose values are exact,
+//! and some other exact or conventional constants.
+//! The measured constants are in the [`codata2018`] and [`codata2022`] modules,
+//! with their standard uncertainties in the nested `uncertainty` module.
+//!
+//! With the `const_soft_float` feature, every module also contains a `soft` module
+//! with the same constants as [`SoftF64`](crate::SoftF64) values.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::constants::{codata2022, C};
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::exported::energy::Joule;
+//! use ucsi::Value;
+//!
+//! let rest_energy: Value<f64, Joule> = (codata2022::M_E * C * C).reinterpret_kind();
+//! assert!((rest_energy.value - 8.187_105_7e-14).abs() < 1e-20);
+//!
+//! let relative = codata2022::uncertainty::G.value / codata2022::G.value;
+//! assert!(relative < 1e-4);
+//! ```
+
+use crate::{
+    unit,
+    units::{
+        base::{Ampere, Candela, Kelvins, Kilogram, Meter, Mole, Second},
+        exported::{energy::Joule, frequency::Hertz},
+    },
+};
+
+/// Define constants, their uncertainties and the `SoftF64` counterparts.
+///
+/// ```rust,ignore
+/// __impl_constants! {
+///     /// docs...
+///     const NAME: UnitType = value;
+///     /// docs...
+///     const NAME: UnitType = value, uncertainty uncertainty_value;
+/// }
+/// ```
+macro_rules! __impl_constants {
+    ($(
+        $(#[$attr:meta])*
+        const $name:ident: $unit:ty = $val:literal $(, uncertainty $unc:literal)?;
+    )+) => {
+        $(
+            $(#[$attr])*
+            pub const $name: $crate::Value<f64, $unit> = $crate::Value::new($val);
+        )+
+
+        /// Standard uncertainties of the measured constants.
+        pub mod uncertainty {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(__impl_constants!(@unc f64 [$(#[$attr])*] $name: $unit $(= $unc)?);)+
+        }
+
+        #[cfg(feature = "const_soft_float")]
+        /// Constants as `SoftF64` values.
+        pub mod soft {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                $(#[$attr])*
+                pub const $name: $crate::Value<$crate::SoftF64, $unit> =
+                    $crate::Value::new($crate::SoftF64($val));
+            )+
+
+            /// Standard uncertainties of the measured constants.
+            pub mod uncertainty {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $(__impl_constants!(@unc soft [$(#[$attr])*] $name: $unit $(= $unc)?);)+
+            }
+        }
+    };
+
+    (@unc $ty:ident [$(#[$attr:meta])*] $name:ident: $unit:ty) => {};
+    (@unc f64 [$(#[$attr:meta])*] $name:ident: $unit:ty = $unc:literal) => {
+        $(#[$attr])*
+        pub const $name: $crate::Value<f64, $unit> = $crate::Value::new($unc);
+    };
+    (@unc soft [$(#[$attr:meta])*] $name:ident: $unit:ty = $unc:literal) => {
+        $(#[$attr])*
+        pub const $name: $crate::Value<$crate::SoftF64, $unit> =
+            $crate::Value::new($crate::SoftF64($unc));
+    };
+}
+
+pub mod codata2018;
+pub mod codata2022;
+
+__impl_constants! {
+    /// Hyperfine transition frequency of Cs-133, `Δν_Cs`. (exact)
+    const DELTA_NU_CS: Hertz = 9_192_631_770.0;
+    /// Speed of light in vacuum, `c`. (exact)
+    const C: unit!(Meter / Second) = 299_792_458.0;
+    /// Planck constant, `h`. (exact)
+    const H: unit!(Joule * Second) = 6.626_070_15e-34;
+    /// Elementary charge, `e`. (exact)
+    const E: unit!(Ampere * Second) = 1.602_176_634e-19;
+    /// Boltzmann constant, `k_B`. (exact)
+    const K_B: unit!(Joule / Kelvins) = 1.380_649e-23;
+    /// Avogadro constant, `N_A`. (exact)
+    const N_A: unit!(Mole ** { -1 }) = 6.022_140_76e23;
+    /// Luminous efficacy of monochromatic radiation of 540 THz, `K_cd`. (exact)
+    ///
+    /// The unit is `lm / W`, the steradian is omitted.
+    const K_CD: unit!((Candela * (Second ** { 3 })) / (Kilogram * (Meter ** { 2 }))) = 683.0;
+
+    /// Reduced Planck constant, `ħ = h / 2π`. (exact, rounded to `f64`)
+    const HBAR: unit!(Joule * Second) = 1.054_571_817_646_156_4e-34;
+    /// Molar gas constant, `R = N_A k_B`. (exact, rounded to `f64`)
+    const R: unit!(Joule / (Mole * Kelvins)) = 8.314_462_618_153_24;
+    /// Faraday constant, `F = N_A e`. (exact, rounded to `f64`)
+    const F: unit!((Ampere * Second) / Mole) = 96_485.332_123_310_02;
+    /// Standard acceleration of gravity, `g_0`. (conventional, exact)
+    const G_0: unit!(Meter / (Second ** { 2 })) = 9.806_65;
+}
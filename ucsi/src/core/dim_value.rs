@@ -0,0 +1,246 @@
+//! # The `dim_value` module
+//!
+//! **Experimental.** This module requires a nightly compiler.
+//!
+//! [`DimValue`] works like [`Value`], but stores the dimension itself
+//! as a const generic [`BaseUnitMap`] instead of a unit type.
+//! Arithmetic computes the resulting dimension with `generic_const_exprs`,
+//! so values with equal dimensions always have equal types and never need a cast.
+//!
+//! ## Example
+//!
+//! ```rust
+//! #![allow(incomplete_features)]
+//! #![feature(adt_const_params, generic_const_exprs)]
+//!
+//! use ucsi::core::dim_value::{dim_of, DimValue};
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::exported::force::Newton;
+//! use ucsi::Value;
+//!
+//! let mass: DimValue<f64, { dim_of::<kg>() }> = DimValue::new(2.0);
+//! let length: DimValue<f64, { dim_of::<m>() }> = DimValue::new(3.0);
+//! let time: DimValue<f64, { dim_of::<s>() }> = DimValue::new(1.0);
+//!
+//! // `kg * m / s / s` has exactly the same type as the newton
+//! let force: DimValue<f64, { dim_of::<Newton>() }> = mass * length / time / time;
+//! let force: Value<f64, Newton> = force.into_value();
+//! assert_eq!(force.value, 6.0);
+//!
+//! // fractional exponents are simplified
+//! let root: DimValue<f64, { dim_of::<ucsi::ops::PowFrac<s, 1, 2>>() }> = DimValue::new(2.0);
+//! let time: DimValue<f64, { dim_of::<s>() }> = root * root;
+//! assert_eq!(time.value, 4.0);
+//! ```
+//!
+//! Converting into a [`Value`] of another dimension cannot compile:
+//!
+//! ```rust,compile_fail
+//! #![allow(incomplete_features)]
+//! #![feature(adt_const_params, generic_const_exprs)]
+//!
+//! # use ucsi::core::dim_value::{dim_of, DimValue};
+//! # use ucsi::units::base::{kg, s};
+//! # use ucsi::Value;
+//! let mass: DimValue<f64, { dim_of::<kg>() }> = DimValue::new(2.0);
+//! let time: Value<f64, s> = mass.into_value();
+//! ```
+//!
+//! ## Related features
+//!
+//! - `const_dim`: Enable this module.
+//!   Requires the `nightly` feature and a nightly compiler.
+//!   (Disabled by default. Not included in the `nightly_full` feature,
+//!   since `generic_const_exprs` is still incomplete.)
+//!
+//! You must enable the following features in your crate to name `DimValue` types:
+//!
+//! ```rust,ignore
+//! #![feature(adt_const_params, generic_const_exprs)]
+//! ```
+
+use core::{fmt, ops};
+
+use crate::{
+    __dbg_assert,
+    units::{any::SiOpsUnit, base::BaseUnitMap},
+    Value,
+};
+
+/// The simplified dimension of a unit.
+///
+/// Use this to name the dimension of a [`DimValue`].
+pub const fn dim_of<U: SiOpsUnit>() -> BaseUnitMap {
+    U::UNIT_MAP.simplify()
+}
+
+// Const generic equality is structural, so every dimension is simplified,
+// e.g. `s^(1/2) * s^(1/2)` must be `s^(1/1)` rather than `s^(4/4)`.
+
+/// The dimension of `L * R`.
+pub const fn dim_mul(lhs: BaseUnitMap, rhs: BaseUnitMap) -> BaseUnitMap {
+    lhs.add(rhs).simplify()
+}
+
+/// The dimension of `L / R`.
+pub const fn dim_div(lhs: BaseUnitMap, rhs: BaseUnitMap) -> BaseUnitMap {
+    lhs.add(rhs.neg()).simplify()
+}
+
+/// The dimension of `B ** { P }`.
+pub const fn dim_powi(base: BaseUnitMap, p: i32) -> BaseUnitMap {
+    base.imul(p).simplify()
+}
+
+/// Units with the dimension `D`.
+///
+/// Works like [`CastFrom`](crate::units::any::CastFrom).
+pub trait CastFromDim<const D: BaseUnitMap> {
+    /// This should be theoretically `true` and should never be `false`.
+    const CAN_CAST_FROM: bool;
+}
+
+impl<U: SiOpsUnit, const D: BaseUnitMap> CastFromDim<D> for U {
+    const CAN_CAST_FROM: bool = {
+        if !U::UNIT_MAP.eq(&D) {
+            panic!("cannot cast si type")
+        }
+        if !U::SCALE.is_one() {
+            panic!("cannot cast a scaled unit, use `rescale` first")
+        }
+        true
+    };
+}
+
+pub struct DimValue<V, const D: BaseUnitMap> {
+    pub value: V,
+}
+
+impl<V: fmt::Debug, const D: BaseUnitMap> fmt::Debug for DimValue<V, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DimValue").field(&self.value).finish()
+    }
+}
+
+impl<V: Clone, const D: BaseUnitMap> Clone for DimValue<V, D> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<V: Copy, const D: BaseUnitMap> Copy for DimValue<V, D> {}
+
+impl<V: PartialEq<L>, L, const D: BaseUnitMap> PartialEq<DimValue<L, D>> for DimValue<V, D> {
+    #[inline]
+    fn eq(&self, other: &DimValue<L, D>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<V, const D: BaseUnitMap> DimValue<V, D> {
+    #[inline]
+    pub const fn new(value: V) -> DimValue<V, D> {
+        Self { value }
+    }
+
+    /// The dimension of the value.
+    #[inline]
+    pub const fn dimension(&self) -> BaseUnitMap {
+        D
+    }
+
+    /// Convert into a [`Value`] with a unit of the same dimension.
+    ///
+    /// The dimension is checked at compile time.
+    #[inline]
+    pub fn into_value<U: SiOpsUnit>(self) -> Value<V, U> {
+        __dbg_assert!(<U as CastFromDim<D>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+
+    #[inline]
+    pub fn powi<const P: i32>(self) -> DimValue<V, { dim_powi(D, P) }> {
+        DimValue::new(self.value)
+    }
+}
+
+impl<V: Copy, const D: BaseUnitMap> DimValue<V, D> {
+    /// Convert into a [`Value`] with a unit of the same dimension.
+    ///
+    /// The dimension is checked at compile time.
+    #[inline]
+    pub const fn into_value_const<U: SiOpsUnit>(self) -> Value<V, U> {
+        __dbg_assert!(<U as CastFromDim<D>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+}
+
+impl<V, U: SiOpsUnit> Value<V, U> {
+    /// Convert into a [`DimValue`].
+    #[inline]
+    pub fn into_dim_value(self) -> DimValue<V, { dim_of::<U>() }> {
+        DimValue::new(self.value)
+    }
+}
+
+impl<V, U: SiOpsUnit> From<Value<V, U>> for DimValue<V, { dim_of::<U>() }> {
+    #[inline]
+    fn from(value: Value<V, U>) -> Self {
+        DimValue::new(value.value)
+    }
+}
+
+impl<V: ops::Add<L>, L, const D: BaseUnitMap> ops::Add<DimValue<L, D>> for DimValue<V, D> {
+    type Output = DimValue<V::Output, D>;
+
+    #[inline]
+    fn add(self, rhs: DimValue<L, D>) -> Self::Output {
+        DimValue::new(self.value.add(rhs.value))
+    }
+}
+
+impl<V: ops::Sub<L>, L, const D: BaseUnitMap> ops::Sub<DimValue<L, D>> for DimValue<V, D> {
+    type Output = DimValue<V::Output, D>;
+
+    #[inline]
+    fn sub(self, rhs: DimValue<L, D>) -> Self::Output {
+        DimValue::new(self.value.sub(rhs.value))
+    }
+}
+
+impl<V: ops::Mul<L>, L, const A: BaseUnitMap, const B: BaseUnitMap> ops::Mul<DimValue<L, B>>
+    for DimValue<V, A>
+where
+    DimValue<V::Output, { dim_mul(A, B) }>: Sized,
+{
+    type Output = DimValue<V::Output, { dim_mul(A, B) }>;
+
+    #[inline]
+    fn mul(self, rhs: DimValue<L, B>) -> Self::Output {
+        DimValue::new(self.value.mul(rhs.value))
+    }
+}
+
+impl<V: ops::Div<L>, L, const A: BaseUnitMap, const B: BaseUnitMap> ops::Div<DimValue<L, B>>
+    for DimValue<V, A>
+where
+    DimValue<V::Output, { dim_div(A, B) }>: Sized,
+{
+    type Output = DimValue<V::Output, { dim_div(A, B) }>;
+
+    #[inline]
+    fn div(self, rhs: DimValue<L, B>) -> Self::Output {
+        DimValue::new(self.value.div(rhs.value))
+    }
+}
+
+impl<V: ops::Neg, const D: BaseUnitMap> ops::Neg for DimValue<V, D> {
+    type Output = DimValue<V::Output, D>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DimValue::new(self.value.neg())
+    }
+}
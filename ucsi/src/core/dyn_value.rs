@@ -0,0 +1,305 @@
+//! # The `dyn_value` module
+//!
+//! [`DynValue`] carries its dimension as a runtime [`BaseUnitMap`] and an exact [`Scale`],
+//! for units that are only known at runtime, e.g. in plugins, scripts or configurations.
+//!
+//! Addition and subtraction return a [`DynError`] if the units differ,
+//! and multiplication and division if an exponent or the scale factor of the result overflows.
+//! A [`DynValue`] is converted from a [`Value`] with [`From`],
+//! and back with [`DynValue::downcast`] after a runtime check.
+//!
+//! A [`DynValue`] does not carry a [quantity kind](crate::core::units::kind),
+//! so the kind of the original unit is dropped, and `downcast` never checks kinds.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::core::dyn_value::{DynError, DynValue};
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::exported::force::Newton;
+//! use ucsi::Value;
+//!
+//! let mass = DynValue::from(Value::<f64, kg>::new(2.0));
+//! let speed = (DynValue::from(Value::<f64, m>::new(3.0)) / DynValue::from(Value::<f64, s>::new(1.0))).unwrap();
+//! let acc = (speed / DynValue::from(Value::<f64, s>::new(1.0))).unwrap();
+//! let force = (mass * acc).unwrap();
+//!
+//! let total = (force + force).unwrap();
+//! let total: Value<f64, Newton> = total.downcast().unwrap();
+//! assert_eq!(total.value, 12.0);
+//!
+//! assert_eq!(mass + force, Err(DynError::DimensionMismatch));
+//! assert!(force.downcast::<kg>().is_err());
+//! ```
+
+use core::{fmt, ops};
+
+use datastruct::DataStruct;
+
+use crate::{
+    core::format::{
+        ufmt::{SiDefault, SiDefaultOption},
+        MapFormat,
+    },
+    units::{any::SiOpsUnit, base::BaseUnitMap, scale::Scale},
+    Value,
+};
+
+/// Errors of runtime unit checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynError {
+    /// The dimensions of the two operands differ.
+    DimensionMismatch,
+    /// The dimensions are equal, but the scale factors differ.
+    ScaleMismatch { expected: Scale, found: Scale },
+    /// An exponent or the scale factor of the result overflows,
+    /// or the result has more than [`ExtraDimensions::CAPACITY`](crate::units::extra::ExtraDimensions::CAPACITY)
+    /// extra dimensions.
+    Overflow,
+}
+
+impl fmt::Display for DynError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynError::DimensionMismatch => write!(f, "dimension mismatch"),
+            DynError::ScaleMismatch { expected, found } => {
+                write!(f, "scale mismatch: expected `{}`, found `{}`", expected, found)
+            }
+            DynError::Overflow => write!(f, "unit exponent or scale factor overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for DynError {}
+
+/// A value with a runtime unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynValue<V> {
+    pub value: V,
+    unit: BaseUnitMap,
+    scale: Scale,
+}
+
+impl<V> DynValue<V> {
+    /// Create a value of the coherent unit of the dimension.
+    #[inline]
+    pub const fn new(value: V, unit: BaseUnitMap) -> DynValue<V> {
+        DynValue::with_scale(value, unit, Scale::ONE)
+    }
+
+    /// Create a value of the unit `scale * unit`.
+    #[inline]
+    pub const fn with_scale(value: V, unit: BaseUnitMap, scale: Scale) -> DynValue<V> {
+        DynValue {
+            value,
+            unit: unit.simplify(),
+            scale,
+        }
+    }
+
+    /// Create a dimensionless value.
+    #[inline]
+    pub const fn dimensionless(value: V) -> DynValue<V> {
+        DynValue::new(value, BaseUnitMap::EMPTY)
+    }
+
+    /// Create a value with the unit of `U`.
+    #[inline]
+    pub const fn of<U: SiOpsUnit>(value: V) -> DynValue<V> {
+        DynValue::with_scale(value, U::UNIT_MAP, U::SCALE)
+    }
+
+    #[inline]
+    pub const fn unit(&self) -> &BaseUnitMap {
+        &self.unit
+    }
+
+    #[inline]
+    pub const fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    /// Check if the two values have the same dimension and scale factor.
+    #[inline]
+    pub const fn is_same_unit<R>(&self, rhs: &DynValue<R>) -> bool {
+        self.unit.eq(&rhs.unit) && self.scale.eq(&rhs.scale)
+    }
+
+    /// Check the unit against `U`.
+    pub const fn check<U: SiOpsUnit>(&self) -> Result<(), DynError> {
+        check_unit(&self.unit, self.scale, &U::UNIT_MAP.simplify(), U::SCALE)
+    }
+
+    /// Convert to a [`Value`] after checking the dimension and the scale factor against `U`.
+    ///
+    /// The [kind](SiOpsUnit::KIND) of `U` is not checked,
+    /// e.g. a torque in newton meters can be downcast to joules.
+    pub fn downcast<U: SiOpsUnit>(self) -> Result<Value<V, U>, DynError> {
+        self.check::<U>()?;
+        Ok(Value::new(self.value))
+    }
+
+    /// Apply a function to the value, keeping the unit.
+    #[inline]
+    pub fn map<R>(self, f: impl FnOnce(V) -> R) -> DynValue<R> {
+        DynValue {
+            value: f(self.value),
+            unit: self.unit,
+            scale: self.scale,
+        }
+    }
+}
+
+const fn check_unit(
+    found: &BaseUnitMap,
+    found_scale: Scale,
+    expected: &BaseUnitMap,
+    expected_scale: Scale,
+) -> Result<(), DynError> {
+    if !found.eq(expected) {
+        Err(DynError::DimensionMismatch)
+    } else if !found_scale.eq(&expected_scale) {
+        Err(DynError::ScaleMismatch {
+            expected: expected_scale,
+            found: found_scale,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+impl<V, U: SiOpsUnit> From<Value<V, U>> for DynValue<V> {
+    #[inline]
+    fn from(value: Value<V, U>) -> Self {
+        DynValue::of::<U>(value.value)
+    }
+}
+
+macro_rules! __impl_float_dyn {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl DynValue<$ty> {
+                /// Convert to the coherent unit of the dimension, i.e. the scale factor `1`.
+                #[inline]
+                pub fn to_coherent(self) -> DynValue<$ty> {
+                    DynValue::new(
+                        self.value * self.scale.numerator() as $ty / self.scale.denominator() as $ty,
+                        self.unit,
+                    )
+                }
+
+                /// Convert to the unit `scale * unit` of the same dimension.
+                #[inline]
+                pub fn rescale(self, scale: Scale) -> DynValue<$ty> {
+                    let factor = self.scale.div(scale);
+                    DynValue::with_scale(
+                        self.value * factor.numerator() as $ty / factor.denominator() as $ty,
+                        self.unit,
+                        scale,
+                    )
+                }
+            }
+
+            #[cfg(feature = "use_std")]
+            impl DynValue<$ty> {
+                /// Raise to the power `n`,
+                /// fails if an exponent of the unit or the scale factor overflows.
+                pub fn powi(self, n: i32) -> Result<DynValue<$ty>, DynError> {
+                    match (self.unit.checked_imul(n), self.scale.checked_powi(n)) {
+                        (Some(unit), Some(scale)) => {
+                            Ok(DynValue::with_scale(self.value.powi(n), unit, scale))
+                        }
+                        _ => Err(DynError::Overflow),
+                    }
+                }
+
+                /// Raise to the power `num / den`, the result is in the coherent unit.
+                ///
+                /// Fails if an exponent of the unit overflows, panics if `den` is zero.
+                pub fn powf(self, num: i32, den: u32) -> Result<DynValue<$ty>, DynError> {
+                    let coherent = self.to_coherent();
+                    match coherent.unit.checked_fmul(crate::fraction::Fraction::new(num, den)) {
+                        Some(unit) => Ok(DynValue::new(coherent.value.powf(num as $ty / den as $ty), unit)),
+                        None => Err(DynError::Overflow),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+__impl_float_dyn!(f32, f64);
+
+impl<V: ops::Add<L>, L> ops::Add<DynValue<L>> for DynValue<V> {
+    type Output = Result<DynValue<V::Output>, DynError>;
+
+    fn add(self, rhs: DynValue<L>) -> Self::Output {
+        check_unit(&rhs.unit, rhs.scale, &self.unit, self.scale)?;
+        Ok(DynValue::with_scale(self.value + rhs.value, self.unit, self.scale))
+    }
+}
+
+impl<V: ops::Sub<L>, L> ops::Sub<DynValue<L>> for DynValue<V> {
+    type Output = Result<DynValue<V::Output>, DynError>;
+
+    fn sub(self, rhs: DynValue<L>) -> Self::Output {
+        check_unit(&rhs.unit, rhs.scale, &self.unit, self.scale)?;
+        Ok(DynValue::with_scale(self.value - rhs.value, self.unit, self.scale))
+    }
+}
+
+/// Combine the unit and the scale factor of a product or quotient.
+const fn checked_unit(unit: Option<BaseUnitMap>, scale: Option<Scale>) -> Result<(BaseUnitMap, Scale), DynError> {
+    match (unit, scale) {
+        (Some(unit), Some(scale)) => Ok((unit, scale)),
+        _ => Err(DynError::Overflow),
+    }
+}
+
+impl<V: ops::Mul<L>, L> ops::Mul<DynValue<L>> for DynValue<V> {
+    type Output = Result<DynValue<V::Output>, DynError>;
+
+    fn mul(self, rhs: DynValue<L>) -> Self::Output {
+        let (unit, scale) = checked_unit(
+            self.unit.checked_add(rhs.unit),
+            self.scale.checked_mul(rhs.scale),
+        )?;
+        Ok(DynValue::with_scale(self.value * rhs.value, unit, scale))
+    }
+}
+
+impl<V: ops::Div<L>, L> ops::Div<DynValue<L>> for DynValue<V> {
+    type Output = Result<DynValue<V::Output>, DynError>;
+
+    fn div(self, rhs: DynValue<L>) -> Self::Output {
+        let unit = match rhs.unit.checked_imul(-1) {
+            Some(recip) => self.unit.checked_add(recip),
+            None => None,
+        };
+        let (unit, scale) = checked_unit(unit, self.scale.checked_div(rhs.scale))?;
+        Ok(DynValue::with_scale(self.value / rhs.value, unit, scale))
+    }
+}
+
+impl<V: ops::Neg> ops::Neg for DynValue<V> {
+    type Output = DynValue<V::Output>;
+
+    fn neg(self) -> Self::Output {
+        DynValue::with_scale(-self.value, self.unit, self.scale)
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for DynValue<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+        if self.unit.eq(&BaseUnitMap::EMPTY) && self.scale.is_one() {
+            return Ok(());
+        }
+        write!(f, " ")?;
+        if self.unit.eq(&BaseUnitMap::EMPTY) {
+            return write!(f, "{} * 1", self.scale);
+        }
+        SiDefault::mfmt(SiDefaultOption::data_default(), self.scale, &self.unit, f)
+    }
+}
@@ -1,10 +1,18 @@
 use core::fmt;
 
-use crate::{SiAnyUnit, Value};
+use crate::{
+    units::{base::BaseUnitMap, scale::Scale},
+    SiAnyUnit,
+};
 
+#[cfg(feature = "use_alloc")]
 extern crate alloc;
+#[cfg(feature = "use_alloc")]
 use alloc::string::String;
 
+#[cfg(feature = "use_alloc")]
+use crate::{core::dyn_value::DynValue, Value};
+
 pub mod ufmt;
 
 pub trait UnitFormat<Unit: SiAnyUnit> {
@@ -13,6 +21,7 @@ pub trait UnitFormat<Unit: SiAnyUnit> {
     fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error>;
 }
 
+#[cfg(feature = "use_alloc")]
 impl<V, T: SiAnyUnit> Value<V, T> {
     pub fn fmt_unit<Fmt: UnitFormat<T>>(&self, op: Fmt::Option) -> String {
         let mut string = String::new();
@@ -21,6 +30,29 @@ impl<V, T: SiAnyUnit> Value<V, T> {
     }
 }
 
+/// Format a unit given at runtime, e.g. the unit of a [`DynValue`].
+///
+/// The built-in formatters implement [`UnitFormat`] with this trait.
+pub trait MapFormat {
+    type Option;
+
+    fn mfmt(
+        op: Self::Option,
+        scale: Scale,
+        map: &BaseUnitMap,
+        w: &mut impl fmt::Write,
+    ) -> Result<(), fmt::Error>;
+}
+
+#[cfg(feature = "use_alloc")]
+impl<V> DynValue<V> {
+    pub fn fmt_unit<Fmt: MapFormat>(&self, op: Fmt::Option) -> String {
+        let mut string = String::new();
+        Fmt::mfmt(op, self.scale(), self.unit(), &mut string).unwrap();
+        string
+    }
+}
+
 pub trait ValueFormat<Unit: SiAnyUnit> {
     type Option;
 
@@ -2,7 +2,11 @@ use core::fmt;
 
 use datastruct::DataStruct;
 
-use crate::{core::format::UnitFormat, fraction::Fraction, units::any::SiOpsUnit};
+use crate::{
+    core::format::{MapFormat, UnitFormat},
+    fraction::Fraction,
+    units::{any::SiOpsUnit, base::BaseUnitMap, scale::Scale},
+};
 
 pub struct SiDefault;
 
@@ -15,13 +19,21 @@ pub struct SiDefaultOption {
     pub ignore_zero: bool,
 }
 
-impl<U: SiOpsUnit> UnitFormat<U> for SiDefault {
+impl MapFormat for SiDefault {
     type Option = SiDefaultOption;
 
-    fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error> {
-        let mut it = U::UNIT_MAP
-            .unit_fields()
-            .into_iter()
+    fn mfmt(
+        op: Self::Option,
+        scale: Scale,
+        map: &BaseUnitMap,
+        w: &mut impl fmt::Write,
+    ) -> Result<(), fmt::Error> {
+        if !scale.is_one() {
+            write!(w, "{} * ", scale)?;
+        }
+
+        let mut it = map
+            .all_fields()
             .filter(|(_, u)| !u.is_zero())
             .peekable();
 
@@ -46,3 +58,12 @@ impl<U: SiOpsUnit> UnitFormat<U> for SiDefault {
         Ok(())
     }
 }
+
+impl<U: SiOpsUnit> UnitFormat<U> for SiDefault {
+    type Option = SiDefaultOption;
+
+    #[inline]
+    fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error> {
+        Self::mfmt(op, U::SCALE, &U::UNIT_MAP, w)
+    }
+}
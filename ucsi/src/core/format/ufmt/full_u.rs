@@ -2,7 +2,11 @@ use core::marker::PhantomData;
 
 use datastruct::DataStruct;
 
-use crate::{core::format::UnitFormat, fraction::Fraction, units::any::SiOpsUnit};
+use crate::{
+    core::format::{MapFormat, UnitFormat},
+    fraction::Fraction,
+    units::{any::SiOpsUnit, base::BaseUnitMap, scale::Scale},
+};
 
 #[derive(Default)]
 pub struct SiFormatter<'a> {
@@ -32,13 +36,21 @@ pub struct SiFormatterOption<'a> {
     pub ignore_zero: bool,
 }
 
-impl<'a, U: SiOpsUnit> UnitFormat<U> for SiFormatter<'a> {
+impl<'a> MapFormat for SiFormatter<'a> {
     type Option = SiFormatterOption<'a>;
 
-    fn ufmt(op: Self::Option, w: &mut impl core::fmt::Write) -> Result<(), core::fmt::Error> {
-        let mut it = U::UNIT_MAP
-            .unit_fields()
-            .into_iter()
+    fn mfmt(
+        op: Self::Option,
+        scale: Scale,
+        map: &BaseUnitMap,
+        w: &mut impl core::fmt::Write,
+    ) -> Result<(), core::fmt::Error> {
+        if !scale.is_one() {
+            write!(w, "{} * ", scale)?;
+        }
+
+        let mut it = map
+            .all_fields()
             .filter(|(_, u)| !u.is_zero())
             .peekable();
 
@@ -67,3 +79,12 @@ impl<'a, U: SiOpsUnit> UnitFormat<U> for SiFormatter<'a> {
         Ok(())
     }
 }
+
+impl<'a, U: SiOpsUnit> UnitFormat<U> for SiFormatter<'a> {
+    type Option = SiFormatterOption<'a>;
+
+    #[inline]
+    fn ufmt(op: Self::Option, w: &mut impl core::fmt::Write) -> Result<(), core::fmt::Error> {
+        Self::mfmt(op, U::SCALE, &U::UNIT_MAP, w)
+    }
+}
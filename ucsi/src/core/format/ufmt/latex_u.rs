@@ -2,7 +2,11 @@ use core::{default, fmt};
 
 use datastruct::{ConstDataStruct, DataStruct};
 
-use crate::{core::format::UnitFormat, fraction::Fraction, units::any::SiOpsUnit};
+use crate::{
+    core::format::{MapFormat, UnitFormat},
+    fraction::Fraction,
+    units::{any::SiOpsUnit, base::BaseUnitMap, scale::Scale},
+};
 
 pub struct SiLatex;
 
@@ -15,6 +19,8 @@ pub struct SiLatexOption {
     pub ignore_zero: bool,
     #[dfield(default = "false")]
     pub plain_fraction: bool,
+    #[dfield(default = r#"" + ""#)]
+    pub unit_seperator: &'static str,
 }
 
 impl default::Default for SiLatexOption {
@@ -23,13 +29,22 @@ impl default::Default for SiLatexOption {
     }
 }
 
-impl<U: SiOpsUnit> UnitFormat<U> for SiLatex {
+impl MapFormat for SiLatex {
     type Option = SiLatexOption;
 
-    fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error> {
-        let mut it = U::UNIT_MAP
-            .unit_fields()
-            .into_iter()
+    fn mfmt(
+        op: Self::Option,
+        scale: Scale,
+        map: &BaseUnitMap,
+        w: &mut impl fmt::Write,
+    ) -> Result<(), fmt::Error> {
+        if !scale.is_one() {
+            scale.format_latex(w)?;
+            write!(w, " \\cdot ")?;
+        }
+
+        let mut it = map
+            .all_fields()
             .filter(|(_, u)| !op.ignore_zero || !u.is_zero())
             .peekable();
 
@@ -49,10 +64,19 @@ impl<U: SiOpsUnit> UnitFormat<U> for SiLatex {
             }
 
             if it.peek().is_some() {
-                write!(w, " + ")?;
+                write!(w, "{}", op.unit_seperator)?;
             }
         }
 
         Ok(())
     }
 }
+
+impl<U: SiOpsUnit> UnitFormat<U> for SiLatex {
+    type Option = SiLatexOption;
+
+    #[inline]
+    fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error> {
+        Self::mfmt(op, U::SCALE, &U::UNIT_MAP, w)
+    }
+}
@@ -1,7 +1,9 @@
 mod default_u;
 mod latex_u;
 mod full_u;
+mod unicode_u;
 
 pub use default_u::*;
 pub use latex_u::*;
 pub use full_u::*;
+pub use unicode_u::*;
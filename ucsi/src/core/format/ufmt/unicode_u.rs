@@ -0,0 +1,87 @@
+use core::fmt;
+
+use datastruct::DataStruct;
+
+use crate::{
+    core::format::{MapFormat, UnitFormat},
+    fraction::Fraction,
+    units::{any::SiOpsUnit, base::BaseUnitMap, scale::Scale},
+};
+
+/// Format units with unicode superscripts, e.g. `kg·m²·s⁻²`.
+pub struct SiUnicode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DataStruct)]
+#[dstruct(default, const, set)]
+pub struct SiUnicodeOption {
+    #[dfield(default = r#""·""#)]
+    pub unit_seperator: &'static str,
+    #[dfield(default = "false")]
+    pub show_all_exponent: bool,
+}
+
+fn write_superscript(w: &mut impl fmt::Write, n: i64) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    if n < 0 {
+        w.write_char('⁻')?;
+    }
+    let n = n.unsigned_abs();
+    let mut div = 1;
+    while div * 10 <= n {
+        div *= 10;
+    }
+    while div > 0 {
+        w.write_char(DIGITS[(n / div % 10) as usize])?;
+        div /= 10;
+    }
+    Ok(())
+}
+
+impl MapFormat for SiUnicode {
+    type Option = SiUnicodeOption;
+
+    fn mfmt(
+        op: Self::Option,
+        scale: Scale,
+        map: &BaseUnitMap,
+        w: &mut impl fmt::Write,
+    ) -> Result<(), fmt::Error> {
+        if !scale.is_one() {
+            write!(w, "{}{}", scale, op.unit_seperator)?;
+        }
+
+        let mut it = map
+            .all_fields()
+            .filter(|(_, u)| !u.is_zero())
+            .peekable();
+
+        while let Some((n, u)) = it.next() {
+            let pow = u.simplify();
+            write!(w, "{}", n)?;
+
+            if op.show_all_exponent || !pow.eq(&Fraction::ONE) {
+                write_superscript(w, pow.numerator() as i64)?;
+                if pow.denominator().get() != 1 {
+                    w.write_char('ᐟ')?;
+                    write_superscript(w, pow.denominator().get() as i64)?;
+                }
+            }
+
+            if it.peek().is_some() {
+                write!(w, "{}", op.unit_seperator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<U: SiOpsUnit> UnitFormat<U> for SiUnicode {
+    type Option = SiUnicodeOption;
+
+    #[inline]
+    fn ufmt(op: Self::Option, w: &mut impl fmt::Write) -> Result<(), fmt::Error> {
+        Self::mfmt(op, U::SCALE, &U::UNIT_MAP, w)
+    }
+}
@@ -7,5 +7,14 @@ pub mod ops;
 /// Values with unit.
 pub mod value;
 
-#[cfg(feature = "use_alloc")]
+/// Physical constants.
+pub mod constants;
+
+/// Values with a runtime unit.
+pub mod dyn_value;
+
+#[cfg(feature = "const_dim")]
+/// Values with a const generic dimension.
+pub mod dim_value;
+
 pub mod format;
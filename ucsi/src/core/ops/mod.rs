@@ -32,6 +32,34 @@
 //! 
 //! Consecutive operations are not currently supported,
 //! so operations with more than two members need to be wrapped in parentheses.
+//! With the `proc_macros` feature, the procedural [`ops::unit`](unit!) macro
+//! supports operator precedence, `^` powers and string input instead.
+//! 
+//! Units with an exact rational factor, e.g. kilometers, are written with
+//! [`Scaled<T, N, D>`](Scaled) outside the macro, see [the `scale` module](crate::units::scale).
+//! 
+//! ## Procedural macro
+//! 
+//! ```rust
+//! # #[cfg(feature = "proc_macros")] {
+//! use core::marker::PhantomData;
+//! use ucsi::ops::{unit, Div, Mul, PowFrac, PowI};
+//! use ucsi::units::base::{kg, m, s};
+//! 
+//! type Newton = unit!(kg * m / s^2);
+//! let _: PhantomData<Div<Mul<kg, m>, PowI<s, 2>>> = PhantomData::<Newton>;
+//! 
+//! type Root = unit!("s¹ᐟ²");
+//! let _: PhantomData<PowFrac<s, 1, 2>> = PhantomData::<Root>;
+//! # }
+//! ```
+//! 
+//! Plain and raw string literals are accepted, byte and C strings are not:
+//! 
+#![cfg_attr(feature = "proc_macros", doc = "```rust,compile_fail")]
+#![cfg_attr(not(feature = "proc_macros"), doc = "```rust,ignore")]
+//! type Newton = ucsi::ops::unit!(b"kg * m / s^2");
+//! ```
 //! 
 //! ## Restrictions
 //! 
@@ -43,3 +71,6 @@ mod ops;
 mod ops_creater;
 
 pub use ops::*;
+
+#[cfg(feature = "proc_macros")]
+pub use ucsi_macros::unit;
@@ -4,8 +4,9 @@ use core::{fmt, marker::PhantomData};
 
 use crate::{
     core::units::{
-        any::{SiAnyUnit, SiOpsUnit},
+        any::{QuantityKind, SiAnyUnit, SiOpsUnit},
         base::BaseUnitMap,
+        scale::Scale,
     },
     fraction::Fraction,
     units::any::SiDisplayableUnit,
@@ -35,6 +36,15 @@ impl<L: SiOpsUnit, R: SiOpsUnit> Mul<L, R> {
 
 impl<L: SiOpsUnit, R: SiOpsUnit> SiOpsUnit for Mul<L, R> {
     const UNIT_MAP: BaseUnitMap = L::UNIT_MAP.add(R::UNIT_MAP);
+    // the kind is kept only when the other side is dimensionless
+    const KIND: Option<QuantityKind> = if R::UNIT_MAP.eq(&BaseUnitMap::EMPTY) {
+        L::KIND
+    } else if L::UNIT_MAP.eq(&BaseUnitMap::EMPTY) {
+        R::KIND
+    } else {
+        None
+    };
+    const SCALE: Scale = L::SCALE.mul(R::SCALE);
 }
 
 impl<L: SiOpsUnit, R: SiOpsUnit> SiAnyUnit for Mul<L, R> {}
@@ -87,6 +97,13 @@ impl<N: SiOpsUnit, D: SiOpsUnit> Div<N, D> {
 
 impl<N: SiOpsUnit, D: SiOpsUnit> SiOpsUnit for Div<N, D> {
     const UNIT_MAP: BaseUnitMap = N::UNIT_MAP.add(D::UNIT_MAP.neg());
+    // the kind is kept only when the denominator is dimensionless
+    const KIND: Option<QuantityKind> = if D::UNIT_MAP.eq(&BaseUnitMap::EMPTY) {
+        N::KIND
+    } else {
+        None
+    };
+    const SCALE: Scale = N::SCALE.div(D::SCALE);
 }
 
 impl<N: SiOpsUnit, D: SiOpsUnit> SiAnyUnit for Div<N, D> {}
@@ -135,6 +152,8 @@ impl<B: SiOpsUnit, const P: i32> PowI<B, P> {
 
 impl<B: SiOpsUnit, const P: i32> SiOpsUnit for PowI<B, P> {
     const UNIT_MAP: BaseUnitMap = B::UNIT_MAP.imul(P);
+    const KIND: Option<QuantityKind> = if P == 1 { B::KIND } else { None };
+    const SCALE: Scale = B::SCALE.powi(P);
 }
 
 impl<B: SiOpsUnit, const P: i32> SiAnyUnit for PowI<B, P> {}
@@ -182,6 +201,12 @@ impl<B: SiOpsUnit, const N: i32, const D: u32> PowFrac<B, N, D> {
 
 impl<B: SiOpsUnit, const N: i32, const D: u32> SiOpsUnit for PowFrac<B, N, D> {
     const UNIT_MAP: BaseUnitMap = B::UNIT_MAP.fmul(Fraction::new(N, D));
+    const KIND: Option<QuantityKind> = if Fraction::new(N, D).eq(&Fraction::ONE) {
+        B::KIND
+    } else {
+        None
+    };
+    const SCALE: Scale = B::SCALE.powf(N, D);
 }
 
 impl<B: SiOpsUnit, const N: i32, const D: u32> SiAnyUnit for PowFrac<B, N, D> {}
@@ -210,3 +235,46 @@ impl<B: SiOpsUnit + SiDisplayableUnit, const N: i32, const D: u32> SiDisplayable
         }
     }
 }
+
+/// `TypeA` scaled by the exact factor `N / D`, e.g. `Scaled<Meter, 1000, 1>` for kilometers.
+///
+/// See [the `scale` module](crate::units::scale) for more information.
+#[derive(Default)]
+pub struct Scaled<U: SiOpsUnit, const N: i64, const D: u64> {
+    _u: PhantomData<U>,
+}
+
+impl<U: SiOpsUnit, const N: i64, const D: u64> Scaled<U, N, D> {
+    /// Create an instance of the `Scaled` type.
+    pub const fn new() -> Scaled<U, N, D> {
+        Scaled { _u: PhantomData }
+    }
+}
+
+impl<U: SiOpsUnit, const N: i64, const D: u64> SiOpsUnit for Scaled<U, N, D> {
+    const UNIT_MAP: BaseUnitMap = U::UNIT_MAP;
+    const KIND: Option<QuantityKind> = U::KIND;
+    const SCALE: Scale = U::SCALE.mul(Scale::new(N, D));
+}
+
+impl<U: SiOpsUnit, const N: i64, const D: u64> SiAnyUnit for Scaled<U, N, D> {}
+
+impl<U: SiOpsUnit + SiDisplayableUnit, const N: i64, const D: u64> SiDisplayableUnit
+    for Scaled<U, N, D>
+{
+    const DISPLAYABLE: bool = true;
+
+    fn display_symbol(w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "{}", Scale::new(N, D))?;
+        if U::DISPLAYABLE {
+            write!(w, " * ")?;
+        }
+        U::display_symbol_wrapped(w)
+    }
+
+    fn display_symbol_wrapped(w: &mut impl fmt::Write) -> fmt::Result {
+        write!(w, "(")?;
+        Self::display_symbol(w)?;
+        write!(w, ")")
+    }
+}
@@ -0,0 +1,115 @@
+//! # The `angle` module
+//!
+//! SI treats plane angles as dimensionless, so by default [`Radian`] is just another
+//! spelling of [`PureValue`] and a function expecting radians accepts any plain number.
+//!
+//! With the `angle` feature, radian becomes a base unit of its own
+//! (an extra field of [`BaseUnitMap`](super::base::BaseUnitMap)),
+//! and angles can no longer be mixed up with dimensionless values.
+//!
+//! Angle units implement [`AngleUnit`], which offers conversion to radians
+//! and, with the `use_std` feature, trigonometric functions.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "associated_types", feature = "use_std"))] {
+//! use ucsi::units::angle::AngleUnit;
+//! use ucsi::units::associated::angle::Degree;
+//! use ucsi::units::base::Radian;
+//! use ucsi::Value;
+//!
+//! let right: Value<f64, Degree> = Value::new(90.0);
+//! let rad: Value<f64, Radian> = right.to_radians();
+//! assert!((rad.value - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+//! assert!((right.sin().value - 1.0).abs() < 1e-12);
+//! # }
+//! ```
+//!
+//! With the `angle` feature, dimensionless values are no longer angles:
+//!
+#![cfg_attr(feature = "angle", doc = "```rust,compile_fail")]
+#![cfg_attr(not(feature = "angle"), doc = "```rust,ignore")]
+//! # use ucsi::units::base::{Radian, PureValue};
+//! # use ucsi::Value;
+//! let ratio: Value<f64, PureValue> = Value::new(0.5);
+//! let angle: Value<f64, Radian> = ratio.cast(); // fails to compile
+//! ```
+//!
+//! ## Related features
+//!
+//! - `angle`: Make radian a base unit.
+//!   (Disabled by default. Not included in the `full` feature, since it changes SI semantics.)
+//!   Angle units are not [`Canonicalize`](super::canonical::Canonicalize) with this feature.
+//! - `use_std`: Enable trigonometric functions.
+
+use super::{
+    any::SiAnyUnit,
+    base::{PureValue, Radian},
+};
+use crate::Value;
+
+/// Units of plane angle.
+pub trait AngleUnit: SiAnyUnit {
+    /// How many radians one unit is.
+    const RADIANS: f64;
+}
+
+impl AngleUnit for Radian {
+    const RADIANS: f64 = 1.0;
+}
+
+impl<U: AngleUnit> Value<f64, U> {
+    /// Convert the angle to radians.
+    #[inline]
+    pub fn to_radians(self) -> Value<f64, Radian> {
+        Value::new(self.value * U::RADIANS)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<U: AngleUnit> Value<f64, U> {
+    #[inline]
+    pub fn sin(self) -> Value<f64, PureValue> {
+        Value::new(self.to_radians().value.sin())
+    }
+
+    #[inline]
+    pub fn cos(self) -> Value<f64, PureValue> {
+        Value::new(self.to_radians().value.cos())
+    }
+
+    #[inline]
+    pub fn tan(self) -> Value<f64, PureValue> {
+        Value::new(self.to_radians().value.tan())
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Value<f64, PureValue> {
+    #[inline]
+    pub fn asin(self) -> Value<f64, Radian> {
+        Value::new(self.value.asin())
+    }
+
+    #[inline]
+    pub fn acos(self) -> Value<f64, Radian> {
+        Value::new(self.value.acos())
+    }
+
+    #[inline]
+    pub fn atan(self) -> Value<f64, Radian> {
+        Value::new(self.value.atan())
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<U: SiAnyUnit> Value<f64, U> {
+    /// Four quadrant arctangent of `self` (`y`) and `x` in radians.
+    ///
+    /// Both sides must have the same unit.
+    #[inline]
+    pub fn atan2(self, x: Value<f64, U>) -> Value<f64, Radian> {
+        Value::new(self.value.atan2(x.value))
+    }
+}
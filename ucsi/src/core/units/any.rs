@@ -1,6 +1,6 @@
 use core::fmt::{self, Write};
 
-use super::base::BaseUnitMap;
+use super::{base::BaseUnitMap, scale::Scale};
 
 pub struct SiDefinedUnitDefinition {
     pub full_name: &'static str,
@@ -26,6 +26,34 @@ impl<T: SiDefinedUnit + SiOpsUnit> SiDisplayableUnit for T {
 
 pub trait SiOpsUnit: SiAnyUnit {
     const UNIT_MAP: BaseUnitMap;
+    /// The kind of quantity, used to separate quantities sharing the same dimension,
+    /// e.g. torque and energy.
+    ///
+    /// `None` means the kind is unspecified, casting to such a unit strips the kind.
+    const KIND: Option<QuantityKind> = None;
+    /// The exact factor relative to the coherent unit of the dimension,
+    /// see [the `scale` module](super::scale).
+    const SCALE: Scale = Scale::ONE;
+}
+
+/// Kind of quantity.
+///
+/// See [the `kind` module](super::kind) for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuantityKind {
+    pub name: &'static str,
+}
+
+impl QuantityKind {
+    #[inline]
+    pub const fn new(name: &'static str) -> QuantityKind {
+        QuantityKind { name }
+    }
+
+    #[inline]
+    pub const fn eq(&self, rhs: &Self) -> bool {
+        super::extra::str_eq(self.name, rhs.name)
+    }
 }
 
 pub trait SiAnyUnit {}
@@ -40,6 +68,42 @@ pub const fn is_same_type_or_panic<T: SiOpsUnit, U: SiOpsUnit>() {
     }
 }
 
+/// Check if the two units have the same [kind](SiOpsUnit::KIND),
+/// including two units without a kind.
+pub const fn is_same_kind<T: SiOpsUnit, U: SiOpsUnit>() -> bool {
+    match (T::KIND, U::KIND) {
+        (Some(t), Some(u)) => t.eq(&u),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Check if a value of `T` can be cast to `U` without changing its [kind](SiOpsUnit::KIND).
+///
+/// Casting to a unit without a kind strips the kind,
+/// but attaching a kind to a value requires `reinterpret_kind`,
+/// otherwise a torque could be cast to energy through a unit without a kind.
+pub const fn can_cast_kind<T: SiOpsUnit, U: SiOpsUnit>() -> bool {
+    U::KIND.is_none() || is_same_kind::<T, U>()
+}
+
+pub const fn can_cast_kind_or_panic<T: SiOpsUnit, U: SiOpsUnit>() {
+    if !can_cast_kind::<T, U>() {
+        panic!("cannot cast between different quantity kinds, use `reinterpret_kind` instead")
+    }
+}
+
+/// Check if the two units have the same [scale factor](SiOpsUnit::SCALE).
+pub const fn is_same_scale<T: SiOpsUnit, U: SiOpsUnit>() -> bool {
+    T::SCALE.eq(&U::SCALE)
+}
+
+pub const fn is_same_scale_or_panic<T: SiOpsUnit, U: SiOpsUnit>() {
+    if !is_same_scale::<T, U>() {
+        panic!("cannot cast between different scale factors, use `rescale` instead")
+    }
+}
+
 pub trait SiDisplayableUnit {
     const DISPLAYABLE: bool;
 
@@ -60,6 +124,22 @@ pub trait CastFrom<T: SiOpsUnit> {
 impl<T: SiOpsUnit, B: SiOpsUnit> CastFrom<T> for B {
     const CAN_CAST_FROM: bool = {
         is_same_type_or_panic::<T, B>();
+        can_cast_kind_or_panic::<T, B>();
+        is_same_scale_or_panic::<T, B>();
+        true
+    };
+}
+
+/// Like [`CastFrom`], but ignores the [kinds](SiOpsUnit::KIND) of the units.
+pub trait ReinterpretFrom<T: SiOpsUnit> {
+    /// This should be theoretically `true` and should never be `false`.
+    const CAN_REINTERPRET_FROM: bool;
+}
+
+impl<T: SiOpsUnit, B: SiOpsUnit> ReinterpretFrom<T> for B {
+    const CAN_REINTERPRET_FROM: bool = {
+        is_same_type_or_panic::<T, B>();
+        is_same_scale_or_panic::<T, B>();
         true
     };
 }
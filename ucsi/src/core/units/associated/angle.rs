@@ -0,0 +1,65 @@
+use core::f64::consts::PI;
+
+use crate::{
+    core::units::{angle::AngleUnit, base::Radian},
+    macros::unit_def::si_associated_unit_def,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Degree
+    based on Radian {
+        full_name: "Degree",
+        short_name: "degree",
+        unit_symbol: "°",
+    }
+    alias deg;
+
+    unit Arcminute
+    based on Radian {
+        full_name: "Arcminute",
+        short_name: "arcminute",
+        unit_symbol: "′",
+    }
+    alias arcmin;
+
+    unit Arcsecond
+    based on Radian {
+        full_name: "Arcsecond",
+        short_name: "arcsecond",
+        unit_symbol: "″",
+    }
+    alias arcsec;
+
+    unit Turn
+    based on Radian {
+        full_name: "Turn",
+        short_name: "turn",
+        unit_symbol: "tr",
+    }
+    alias tr;
+}
+
+impl AngleUnit for Degree {
+    const RADIANS: f64 = PI / 180.0;
+}
+
+impl AngleUnit for Arcminute {
+    const RADIANS: f64 = PI / 10_800.0;
+}
+
+impl AngleUnit for Arcsecond {
+    const RADIANS: f64 = PI / 648_000.0;
+}
+
+impl AngleUnit for Turn {
+    const RADIANS: f64 = 2.0 * PI;
+}
+
+float_conversion! {
+    Degree: <Degree as AngleUnit>::RADIANS;
+    Arcminute: <Arcminute as AngleUnit>::RADIANS;
+    Arcsecond: <Arcsecond as AngleUnit>::RADIANS;
+    Turn: <Turn as AngleUnit>::RADIANS;
+}
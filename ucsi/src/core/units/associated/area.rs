@@ -0,0 +1,21 @@
+use crate::{
+    core::units::base::Meter,
+    macros::unit_def::si_associated_unit_def,
+    unit,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Hectare
+    based on unit!(Meter ** { 2 }) {
+        full_name: "Hectare",
+        short_name: "hectare",
+        unit_symbol: "ha",
+    }
+    alias ha;
+}
+
+rational_conversion! {
+    Hectare: 10_000 / 1;
+}
@@ -0,0 +1,104 @@
+//! Astronomy and geodesy units.
+//!
+//! Lengths and times are exact by definition (IAU 2012 B2 and the Julian year),
+//! the masses are derived from the IAU 2015 B3 nominal values of `GM`
+//! and the Newtonian constant of gravitation `G` in CODATA 2018.
+//!
+//! With the `const_soft_float` feature, every unit has a `SoftF64` conversion
+//! usable in `const` contexts.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::associated::astronomy::{LightYear, Parsec};
+//! use ucsi::units::base::m;
+//! use ucsi::{SoftF64, Value};
+//!
+//! const DISTANCE: Value<SoftF64, m> = Value::<SoftF64, Parsec>::new(SoftF64(1.3)).to_metric();
+//! let light_years = Value::<f64, LightYear>::from_metric(Value::new(DISTANCE.value.to_f64()));
+//! assert!((light_years.value - 4.24).abs() < 1e-2);
+//! ```
+
+use crate::{
+    core::units::base::{Kilogram, Meter, Second},
+    macros::unit_def::si_associated_unit_def,
+};
+
+pub use super::{angle::Arcsecond, length::AstronomicalUnit};
+
+/// Nominal solar mass parameter `GM`, in `m^3 / s^2`.
+const GM_SUN: f64 = 1.327_124_4e20;
+/// Nominal terrestrial mass parameter `GM`, in `m^3 / s^2`.
+const GM_EARTH: f64 = 3.986_004e14;
+/// Mean sidereal day, in `s`.
+const SIDEREAL_DAY: f64 = 86_164.090_5;
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Parsec, exactly `648 000 / π au`.
+    unit Parsec
+    based on Meter {
+        full_name: "Parsec",
+        short_name: "parsec",
+        unit_symbol: "pc",
+    }
+    alias pc;
+
+    /// Light-year, exactly `9 460 730 472 580 800 m`.
+    unit LightYear
+    based on Meter {
+        full_name: "Light-year",
+        short_name: "light-year",
+        unit_symbol: "ly",
+    }
+    alias ly;
+
+    /// Nominal solar mass, `GM☉ / G`.
+    unit SolarMass
+    based on Kilogram {
+        full_name: "Solar mass",
+        short_name: "solar mass",
+        unit_symbol: "M☉",
+    }
+    alias M_sun;
+
+    /// Nominal Earth mass, `GM⊕ / G`.
+    unit EarthMass
+    based on Kilogram {
+        full_name: "Earth mass",
+        short_name: "Earth mass",
+        unit_symbol: "M⊕",
+    }
+    alias M_earth;
+
+    /// Julian year, exactly `365.25 d`.
+    unit JulianYear
+    based on Second {
+        full_name: "Julian year",
+        short_name: "Julian year",
+        unit_symbol: "a",
+    }
+    alias a;
+
+    /// Mean sidereal day, `86 164.0905 s`.
+    unit SiderealDay
+    based on Second {
+        full_name: "Sidereal day",
+        short_name: "sidereal day",
+        unit_symbol: "d_sid",
+    }
+    alias d_sid;
+}
+
+rational_conversion! {
+    LightYear: 9_460_730_472_580_800 / 1;
+    JulianYear: 31_557_600 / 1;
+}
+
+float_conversion! {
+    Parsec: 149_597_870_700.0 * 648_000.0 / core::f64::consts::PI;
+    SolarMass: GM_SUN / crate::constants::codata2018::G.value;
+    EarthMass: GM_EARTH / crate::constants::codata2018::G.value;
+    SiderealDay: SIDEREAL_DAY;
+}
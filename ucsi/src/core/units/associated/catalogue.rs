@@ -0,0 +1,122 @@
+// @generated by `ucsi::catalogue`, do not edit.
+
+// Generated from `nautical.toml`.
+#[doc = " Nautical units."]
+pub mod nautical {
+    #[allow(unused_imports)]
+    use crate::core::units::base::*;
+    use crate::core::units::registry::UnitEntry;
+
+    crate::__impl_si_associated_unit_def! {
+        alias pub(crate) mod impl_aliases;
+
+        unit NauticalMile
+        based on crate::unit!(Meter) {
+            full_name: "Nautical mile",
+            short_name: "nautical mile",
+            unit_symbol: "NM",
+        }
+        alias NM, nmi;
+
+        #[doc = " Knot, one nautical mile per hour."]
+        unit Knot
+        based on crate::unit!(Meter / Second) {
+            full_name: "Knot",
+            short_name: "knot",
+            unit_symbol: "kn",
+        }
+        alias kn;
+
+        #[doc = " Fathom, six international feet."]
+        unit Fathom
+        based on crate::unit!(Meter) {
+            full_name: "Fathom",
+            short_name: "fathom",
+            unit_symbol: "ftm",
+        }
+        alias ftm;
+    }
+
+    rational_conversion! {
+        NauticalMile: 1852 / 1;
+    }
+
+    rational_conversion! {
+        Knot: 1852 / 3600;
+    }
+
+    rational_conversion! {
+        Fathom: 18_288 / 10_000;
+    }
+
+    /// Registry entries of the units in this module.
+    pub const UNITS: &[UnitEntry] = &[
+        UnitEntry::exact::<NauticalMile>(),
+        UnitEntry::exact::<Knot>(),
+        UnitEntry::exact::<Fathom>(),
+    ];
+}
+
+// Generated from `temperature.toml`.
+#[doc = " Temperature scales besides the kelvin and the degree Fahrenheit."]
+pub mod temperature {
+    #[allow(unused_imports)]
+    use crate::core::units::base::*;
+    use crate::core::units::registry::UnitEntry;
+
+    crate::__impl_si_associated_unit_def! {
+        alias pub(crate) mod impl_aliases;
+
+        #[doc = " Degree Celsius, `T(°C) = T(K) - 273.15`."]
+        #[doc = " "]
+        #[doc = " The conversion is affine, and only available for floats."]
+        unit Celsius
+        based on crate::unit!(Kelvins) {
+            full_name: "Degree Celsius",
+            short_name: "degree celsius",
+            unit_symbol: "°C",
+        }
+        alias degC;
+
+        #[doc = " Degree Rankine, the absolute scale with the size of the degree Fahrenheit."]
+        unit Rankine
+        based on crate::unit!(Kelvins) {
+            full_name: "Degree Rankine",
+            short_name: "degree rankine",
+            unit_symbol: "°R",
+        }
+        alias degR;
+    }
+
+    const CELSIUS_FACTOR: f64 = 1.0;
+    const CELSIUS_OFFSET: f64 = 273.15;
+    const CELSIUS_SCALE: f64 = <<Celsius as crate::core::units::associated::SiAssociatedUnit>::BaseUnit as crate::core::units::any::SiOpsUnit>::SCALE.to_f64();
+
+    affine_conversion! {
+        Celsius: CELSIUS_FACTOR, CELSIUS_OFFSET;
+    }
+
+    rational_conversion! {
+        Rankine: 5 / 9;
+    }
+
+    /// Registry entries of the units in this module.
+    pub const UNITS: &[UnitEntry] = &[
+        UnitEntry::affine::<Celsius>(
+            CELSIUS_SCALE * CELSIUS_FACTOR,
+            CELSIUS_SCALE * CELSIUS_OFFSET,
+        ),
+        UnitEntry::exact::<Rankine>(),
+    ];
+}
+
+mod __collect_catalogue {
+    pub use super::nautical::impl_aliases::*;
+    pub use super::temperature::impl_aliases::*;
+}
+
+/// Registry entries of all catalogue modules.
+pub(crate) const CATALOGUE_UNITS: &[&[crate::core::units::registry::UnitEntry]] = &[
+    nautical::UNITS,
+    temperature::UNITS,
+];
@@ -0,0 +1,60 @@
+use crate::{
+    core::units::exported::energy::Joule,
+    macros::unit_def::si_associated_unit_def,
+};
+
+pub use super::imperial::BritishThermalUnit;
+/// Erg, exactly `1e-7 J`, defined in the [CGS system](crate::units::system::cgs).
+///
+/// Convert it with [`convert_system`](crate::Value::convert_system) and [`Cgs`](crate::units::system::cgs::Cgs).
+pub use crate::units::system::cgs::Erg;
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Electronvolt, exactly `1.602 176 634e-19 J`.
+    unit ElectronVolt
+    based on Joule {
+        full_name: "Electronvolt",
+        short_name: "electronvolt",
+        unit_symbol: "eV",
+    }
+    alias eV;
+
+    /// Thermochemical calorie, exactly `4.184 J`.
+    unit Calorie
+    based on Joule {
+        full_name: "Calorie",
+        short_name: "calorie",
+        unit_symbol: "cal",
+    }
+    alias cal;
+
+    /// Thermochemical kilocalorie, exactly `4184 J`.
+    unit Kilocalorie
+    based on Joule {
+        full_name: "Kilocalorie",
+        short_name: "kilocalorie",
+        unit_symbol: "kcal",
+    }
+    alias kcal;
+
+    /// Kilowatt hour, exactly `3.6 MJ`.
+    unit KilowattHour
+    based on Joule {
+        full_name: "Kilowatt hour",
+        short_name: "kilowatt hour",
+        unit_symbol: "kWh",
+    }
+    alias kWh;
+}
+
+rational_conversion! {
+    Calorie: 4_184 / 1_000;
+    Kilocalorie: 4_184 / 1;
+    KilowattHour: 3_600_000 / 1;
+}
+
+float_conversion! {
+    ElectronVolt: crate::constants::E.value;
+}
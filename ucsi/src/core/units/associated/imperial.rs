@@ -0,0 +1,153 @@
+//! Imperial units.
+//!
+//! Lengths and masses use the international yard and pound of 1959,
+//! `1 in = 0.0254 m` and `1 lb = 0.453 592 37 kg`, shared with the [US customary units](super::us_customary).
+//! Imperial volumes are based on `1 gal = 4.546 09 L`,
+//! and are different types from the US ones.
+
+use crate::{
+    core::units::{
+        base::{Kelvins, Kilogram, Meter},
+        exported::{energy::Joule, pressure::Pascal},
+    },
+    macros::unit_def::si_associated_unit_def,
+    unit,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Inch
+    based on Meter {
+        full_name: "Inch",
+        short_name: "inch",
+        unit_symbol: "in",
+    }
+    alias inch;
+
+    unit Foot
+    based on Meter {
+        full_name: "Foot",
+        short_name: "foot",
+        unit_symbol: "ft",
+    }
+    alias ft;
+
+    unit Yard
+    based on Meter {
+        full_name: "Yard",
+        short_name: "yard",
+        unit_symbol: "yd",
+    }
+    alias yd;
+
+    unit Mile
+    based on Meter {
+        full_name: "Mile",
+        short_name: "mile",
+        unit_symbol: "mi",
+    }
+    alias mi;
+
+    unit Pound
+    based on Kilogram {
+        full_name: "Pound",
+        short_name: "pound",
+        unit_symbol: "lb",
+    }
+    alias lb;
+
+    unit Ounce
+    based on Kilogram {
+        full_name: "Ounce",
+        short_name: "ounce",
+        unit_symbol: "oz",
+    }
+    alias oz;
+
+    unit Stone
+    based on Kilogram {
+        full_name: "Stone",
+        short_name: "stone",
+        unit_symbol: "st",
+    }
+    alias st;
+
+    unit ImperialGallon
+    based on unit!(Meter ** { 3 }) {
+        full_name: "Imperial gallon",
+        short_name: "imperial gallon",
+        unit_symbol: "imp gal",
+    }
+    alias imp_gal;
+
+    unit ImperialPint
+    based on unit!(Meter ** { 3 }) {
+        full_name: "Imperial pint",
+        short_name: "imperial pint",
+        unit_symbol: "imp pt",
+    }
+    alias imp_pt;
+
+    unit ImperialFluidOunce
+    based on unit!(Meter ** { 3 }) {
+        full_name: "Imperial fluid ounce",
+        short_name: "imperial fluid ounce",
+        unit_symbol: "imp fl oz",
+    }
+    alias imp_fl_oz;
+
+    /// Pound-force per square inch.
+    unit PoundPerSquareInch
+    based on Pascal {
+        full_name: "Pound per square inch",
+        short_name: "pound per square inch",
+        unit_symbol: "psi",
+    }
+    alias psi;
+
+    /// International Table British thermal unit.
+    unit BritishThermalUnit
+    based on Joule {
+        full_name: "British thermal unit",
+        short_name: "british thermal unit",
+        unit_symbol: "BTU",
+    }
+    alias Btu;
+
+    /// Degree Fahrenheit, `T(°F) = T(K) * 9/5 - 459.67`.
+    ///
+    /// The conversion is affine, and only available for floats.
+    unit Fahrenheit
+    based on Kelvins {
+        full_name: "Degree Fahrenheit",
+        short_name: "degree fahrenheit",
+        unit_symbol: "°F",
+    }
+    alias degF;
+}
+
+rational_conversion! {
+    Inch: 254 / 10_000;
+    Foot: 3_048 / 10_000;
+    Yard: 9_144 / 10_000;
+    Mile: 1_609_344 / 1_000;
+    Pound: 45_359_237 / 100_000_000;
+    Ounce: 28_349_523_125 / 1_000_000_000_000;
+    Stone: 635_029_318 / 100_000_000;
+    ImperialGallon: 454_609 / 100_000_000;
+    ImperialPint: 56_826_125 / 100_000_000_000;
+    ImperialFluidOunce: 284_130_625 / 10_000_000_000_000;
+    // 0.453 592 37 kg * 9.806 65 m/s^2 / (0.0254 m)^2
+    PoundPerSquareInch: 44_482_216_152_605 / 6_451_600_000;
+    BritishThermalUnit: 105_505_585_262 / 100_000_000;
+}
+
+/// `1 °F = 5/9 K`.
+pub(crate) const FAHRENHEIT_FACTOR: f64 = 5.0 / 9.0;
+/// `0 °F = 459.67 * 5/9 K`.
+pub(crate) const FAHRENHEIT_OFFSET: f64 = 459.67 * FAHRENHEIT_FACTOR;
+
+affine_conversion! {
+    Fahrenheit: FAHRENHEIT_FACTOR, FAHRENHEIT_OFFSET;
+}
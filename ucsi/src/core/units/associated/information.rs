@@ -0,0 +1,245 @@
+//! Information units and data rates.
+//!
+//! [`Bit`] is declared as an extra base dimension (see [`extra`](crate::units::extra)),
+//! so information can not be mixed up with dimensionless counts.
+//! Bytes, decimal prefixes (`kB`, `MB`, ...) and IEC binary prefixes (`KiB`, `MiB`, ...)
+//! are associated units of [`Bit`], and rates are associated units of [`BitPerSecond`].
+//!
+//! Integer conversions are exact and available in `const` contexts.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::associated::information::{Bit, Kibibyte, Kilobyte};
+//! use ucsi::Value;
+//!
+//! const BITS: Value<u64, Bit> = Value::<u64, Kibibyte>::new(1000).to_metric();
+//! const KB: Value<u64, Kilobyte> = Value::<u64, Kilobyte>::from_metric(BITS);
+//! assert_eq!(KB.value, 1024);
+//! ```
+
+use crate::{
+    core::units::base::Second,
+    macros::unit_def::{si_associated_unit_def, si_base_unit_def},
+    unit,
+};
+
+si_base_unit_def! {
+    alias pub(crate) mod impl_base_aliases;
+
+    /// Bit, the base unit of information.
+    unit Bit {
+        full_name: "Bit",
+        short_name: "bit",
+        unit_symbol: "bit",
+    } alias bit;
+}
+
+/// Bit per second, the base unit of data rate.
+pub type BitPerSecond = unit!(Bit / Second);
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Byte, exactly `8 bit`.
+    unit Byte
+    based on Bit {
+        full_name: "Byte",
+        short_name: "byte",
+        unit_symbol: "B",
+    }
+    alias B;
+
+    unit Kilobit
+    based on Bit {
+        full_name: "Kilobit",
+        short_name: "kilobit",
+        unit_symbol: "kbit",
+    }
+    alias kbit;
+
+    unit Megabit
+    based on Bit {
+        full_name: "Megabit",
+        short_name: "megabit",
+        unit_symbol: "Mbit",
+    }
+    alias Mbit;
+
+    unit Gigabit
+    based on Bit {
+        full_name: "Gigabit",
+        short_name: "gigabit",
+        unit_symbol: "Gbit",
+    }
+    alias Gbit;
+
+    unit Terabit
+    based on Bit {
+        full_name: "Terabit",
+        short_name: "terabit",
+        unit_symbol: "Tbit",
+    }
+    alias Tbit;
+
+    unit Kilobyte
+    based on Bit {
+        full_name: "Kilobyte",
+        short_name: "kilobyte",
+        unit_symbol: "kB",
+    }
+    alias kB;
+
+    unit Megabyte
+    based on Bit {
+        full_name: "Megabyte",
+        short_name: "megabyte",
+        unit_symbol: "MB",
+    }
+    alias MB;
+
+    unit Gigabyte
+    based on Bit {
+        full_name: "Gigabyte",
+        short_name: "gigabyte",
+        unit_symbol: "GB",
+    }
+    alias GB;
+
+    unit Terabyte
+    based on Bit {
+        full_name: "Terabyte",
+        short_name: "terabyte",
+        unit_symbol: "TB",
+    }
+    alias TB;
+
+    unit Petabyte
+    based on Bit {
+        full_name: "Petabyte",
+        short_name: "petabyte",
+        unit_symbol: "PB",
+    }
+    alias PB;
+
+    unit Kibibyte
+    based on Bit {
+        full_name: "Kibibyte",
+        short_name: "kibibyte",
+        unit_symbol: "KiB",
+    }
+    alias KiB;
+
+    unit Mebibyte
+    based on Bit {
+        full_name: "Mebibyte",
+        short_name: "mebibyte",
+        unit_symbol: "MiB",
+    }
+    alias MiB;
+
+    unit Gibibyte
+    based on Bit {
+        full_name: "Gibibyte",
+        short_name: "gibibyte",
+        unit_symbol: "GiB",
+    }
+    alias GiB;
+
+    unit Tebibyte
+    based on Bit {
+        full_name: "Tebibyte",
+        short_name: "tebibyte",
+        unit_symbol: "TiB",
+    }
+    alias TiB;
+
+    unit Pebibyte
+    based on Bit {
+        full_name: "Pebibyte",
+        short_name: "pebibyte",
+        unit_symbol: "PiB",
+    }
+    alias PiB;
+
+    unit KilobitPerSecond
+    based on BitPerSecond {
+        full_name: "Kilobit per second",
+        short_name: "kilobit per second",
+        unit_symbol: "kbit/s",
+    }
+    alias kbps;
+
+    unit MegabitPerSecond
+    based on BitPerSecond {
+        full_name: "Megabit per second",
+        short_name: "megabit per second",
+        unit_symbol: "Mbit/s",
+    }
+    alias Mbps;
+
+    unit GigabitPerSecond
+    based on BitPerSecond {
+        full_name: "Gigabit per second",
+        short_name: "gigabit per second",
+        unit_symbol: "Gbit/s",
+    }
+    alias Gbps;
+
+    unit BytePerSecond
+    based on BitPerSecond {
+        full_name: "Byte per second",
+        short_name: "byte per second",
+        unit_symbol: "B/s",
+    }
+    alias Bps;
+
+    unit KilobytePerSecond
+    based on BitPerSecond {
+        full_name: "Kilobyte per second",
+        short_name: "kilobyte per second",
+        unit_symbol: "kB/s",
+    }
+    alias kBps;
+
+    unit MegabytePerSecond
+    based on BitPerSecond {
+        full_name: "Megabyte per second",
+        short_name: "megabyte per second",
+        unit_symbol: "MB/s",
+    }
+    alias MBps;
+
+    unit GigabytePerSecond
+    based on BitPerSecond {
+        full_name: "Gigabyte per second",
+        short_name: "gigabyte per second",
+        unit_symbol: "GB/s",
+    }
+    alias GBps;
+}
+
+rational_conversion! {
+    Byte: 8 / 1;
+    Kilobit: 1_000 / 1;
+    Megabit: 1_000_000 / 1;
+    Gigabit: 1_000_000_000 / 1;
+    Terabit: 1_000_000_000_000 / 1;
+    Kilobyte: 8_000 / 1;
+    Megabyte: 8_000_000 / 1;
+    Gigabyte: 8_000_000_000 / 1;
+    Terabyte: 8_000_000_000_000 / 1;
+    Petabyte: 8_000_000_000_000_000 / 1;
+    Kibibyte: 8_192 / 1;
+    Mebibyte: 8_388_608 / 1;
+    Gibibyte: 8_589_934_592 / 1;
+    Tebibyte: 8_796_093_022_208 / 1;
+    Pebibyte: 9_007_199_254_740_992 / 1;
+    KilobitPerSecond: 1_000 / 1;
+    MegabitPerSecond: 1_000_000 / 1;
+    GigabitPerSecond: 1_000_000_000 / 1;
+    BytePerSecond: 8 / 1;
+    KilobytePerSecond: 8_000 / 1;
+    MegabytePerSecond: 8_000_000 / 1;
+    GigabytePerSecond: 8_000_000_000 / 1;
+}
@@ -0,0 +1,21 @@
+use crate::{
+    core::units::base::Meter,
+    macros::unit_def::si_associated_unit_def,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Astronomical unit, exactly `149 597 870 700 m`.
+    unit AstronomicalUnit
+    based on Meter {
+        full_name: "Astronomical unit",
+        short_name: "astronomical unit",
+        unit_symbol: "au",
+    }
+    alias au;
+}
+
+rational_conversion! {
+    AstronomicalUnit: 149_597_870_700 / 1;
+}
@@ -0,0 +1,107 @@
+//! Units of logarithmic ratio quantities.
+//!
+//! The neper is the coherent unit, `1 B = (1/2) ln 10 Np`, see the SI Brochure, table 8.
+//! Gains convert between each other with `to_metric` and `from_metric`.
+//!
+//! Levels relative to a reference quantity implement [`LevelUnit`],
+//! see the [`level`](crate::units::level) module.
+
+use core::f64::consts::LN_10;
+
+use crate::{
+    core::units::{
+        base::PureValue,
+        exported::{power::Watt, pressure::Pascal, voltage::Volt},
+        level::LevelUnit,
+    },
+    macros::unit_def::si_associated_unit_def,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Neper
+    based on PureValue {
+        full_name: "Neper",
+        short_name: "neper",
+        unit_symbol: "Np",
+    }
+    alias Np;
+
+    unit Bel
+    based on PureValue {
+        full_name: "Bel",
+        short_name: "bel",
+        unit_symbol: "B",
+    };
+
+    unit Decibel
+    based on PureValue {
+        full_name: "Decibel",
+        short_name: "decibel",
+        unit_symbol: "dB",
+    }
+    alias dB;
+
+    /// Power level relative to `1 mW`.
+    unit DecibelMilliwatt
+    based on Watt {
+        full_name: "Decibel-milliwatt",
+        short_name: "decibel-milliwatt",
+        unit_symbol: "dBm",
+    }
+    alias dBm;
+
+    /// Power level relative to `1 W`.
+    unit DecibelWatt
+    based on Watt {
+        full_name: "Decibel-watt",
+        short_name: "decibel-watt",
+        unit_symbol: "dBW",
+    }
+    alias dBW;
+
+    /// Voltage level relative to `1 V`.
+    unit DecibelVolt
+    based on Volt {
+        full_name: "Decibel-volt",
+        short_name: "decibel-volt",
+        unit_symbol: "dBV",
+    }
+    alias dBV;
+
+    /// Sound pressure level relative to `20 µPa`.
+    unit DecibelSoundPressure
+    based on Pascal {
+        full_name: "Decibel sound pressure level",
+        short_name: "decibel sound pressure level",
+        unit_symbol: "dB SPL",
+    }
+    alias dB_SPL;
+}
+
+impl LevelUnit for DecibelMilliwatt {
+    const REFERENCE: f64 = 1e-3;
+    const ROOT_POWER: bool = false;
+}
+
+impl LevelUnit for DecibelWatt {
+    const REFERENCE: f64 = 1.0;
+    const ROOT_POWER: bool = false;
+}
+
+impl LevelUnit for DecibelVolt {
+    const REFERENCE: f64 = 1.0;
+    const ROOT_POWER: bool = true;
+}
+
+impl LevelUnit for DecibelSoundPressure {
+    const REFERENCE: f64 = 2e-5;
+    const ROOT_POWER: bool = true;
+}
+
+float_conversion! {
+    Neper: 1.0;
+    Bel: LN_10 / 2.0;
+    Decibel: LN_10 / 20.0;
+}
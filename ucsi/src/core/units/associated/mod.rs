@@ -19,10 +19,169 @@ pub trait SiAssociatedUnit: SiAnyUnit {
     //     const fn from_metric(val: T) -> Self;
 }
 
+/// Generate float conversions of associated units, `1 unit = factor base unit`.
+macro_rules! float_conversion {
+    ($($unit:ty: $factor:expr;)+) => {
+        $(
+            $crate::macros::conversion::unit_conversion! {
+                $unit {
+                    const (#[cfg(feature = "const_soft_float")] $crate::SoftF32) {
+                        to: |value| { value.mul($crate::SoftF32(($factor) as f32)) },
+                        from: |value| { value.div($crate::SoftF32(($factor) as f32)) },
+                    };
+                    const (#[cfg(feature = "const_soft_float")] $crate::SoftF64) {
+                        to: |value| { value.mul($crate::SoftF64($factor)) },
+                        from: |value| { value.div($crate::SoftF64($factor)) },
+                    };
+                    (f32) {
+                        to: |value| { value * ($factor) as f32 },
+                        from: |value| { value / ($factor) as f32 },
+                    };
+                    (f64) {
+                        to: |value| { value * $factor },
+                        from: |value| { value / $factor },
+                    };
+                };
+            }
+
+            $crate::__impl_linear_unit! {
+                $unit: $factor;
+            }
+        )+
+    };
+}
+
+/// Declare the exact factors of associated units, `1 unit = num / den base unit`,
+/// see [`ScaledUnit`](super::scale::ScaledUnit).
+macro_rules! exact_scale {
+    ($($tt:tt)+) => {
+        $crate::__impl_exact_scale! { $($tt)+ }
+    };
+}
+
+/// Generate float conversions of affine associated units,
+/// `x unit = (x * factor + offset) base unit`.
+macro_rules! affine_conversion {
+    ($($unit:ty: $factor:expr, $offset:expr;)+) => {
+        $(
+            $crate::macros::conversion::unit_conversion! {
+                $unit {
+                    const (#[cfg(feature = "const_soft_float")] $crate::SoftF32) {
+                        to: |value| {
+                            value
+                                .mul($crate::SoftF32(($factor) as f32))
+                                .add($crate::SoftF32(($offset) as f32))
+                        },
+                        from: |value| {
+                            value
+                                .sub($crate::SoftF32(($offset) as f32))
+                                .div($crate::SoftF32(($factor) as f32))
+                        },
+                    };
+                    const (#[cfg(feature = "const_soft_float")] $crate::SoftF64) {
+                        to: |value| { value.mul($crate::SoftF64($factor)).add($crate::SoftF64($offset)) },
+                        from: |value| { value.sub($crate::SoftF64($offset)).div($crate::SoftF64($factor)) },
+                    };
+                    (f32) {
+                        to: |value| { value * ($factor) as f32 + ($offset) as f32 },
+                        from: |value| { (value - ($offset) as f32) / ($factor) as f32 },
+                    };
+                    (f64) {
+                        to: |value| { value * $factor + $offset },
+                        from: |value| { (value - $offset) / $factor },
+                    };
+                };
+            }
+        )+
+    };
+}
+
+/// Generate integer and float conversions of associated units,
+/// `1 unit = numerator / denominator base unit`.
+///
+/// Integer conversions are computed in `i128` and truncated towards zero,
+/// they panic if the result does not fit into the integer type.
+macro_rules! rational_conversion {
+    (@int $unit:ty: $num:literal / $den:literal; $($ity:ty),+) => {
+        $crate::macros::conversion::unit_conversion! {
+            $unit {
+                $(const ($ity) {
+                    to: |value| {
+                        $crate::core::units::scale::checked_int_rescale!(
+                            value,
+                            $ity,
+                            $crate::core::units::scale::Scale::new($num, $den)
+                        )
+                    },
+                    from: |value| {
+                        $crate::core::units::scale::checked_int_rescale!(
+                            value,
+                            $ity,
+                            $crate::core::units::scale::Scale::new($num, $den).recip()
+                        )
+                    },
+                };)+
+            };
+        }
+    };
+    ($($unit:ty: $num:literal / $den:literal;)+) => {
+        $(
+            rational_conversion! {
+                @int $unit: $num / $den;
+                u16, u32, u64, u128, i16, i32, i64, i128, usize, isize
+            }
+        )+
+
+        exact_scale! {
+            $($unit: $num / $den;)+
+        }
+
+        float_conversion! {
+            $($unit: {
+                const NUM: i128 = $num;
+                const DEN: i128 = $den;
+                NUM as f64 / DEN as f64
+            };)+
+        }
+    };
+}
+
+pub mod angle;
+pub mod area;
+pub mod astronomy;
+pub mod energy;
+pub mod imperial;
+pub mod information;
+pub mod length;
+pub mod logarithmic;
+pub mod power;
+pub mod pressure;
+pub mod time;
+pub mod us_customary;
+pub mod volume;
 pub mod weight;
 
+// Modules generated from `catalogue/*.toml` by the `catalogue` module,
+// `ucsi-test/tests/test_catalogue.rs` checks that the file is up to date.
+include!("catalogue.rs");
+
 mod __collect_impl {
+    pub use super::angle::impl_aliases::*;
+    pub use super::area::impl_aliases::*;
+    pub use super::astronomy::impl_aliases::*;
+    pub use super::energy::impl_aliases::*;
+    pub use super::imperial::impl_aliases::*;
+    pub use super::information::impl_aliases::*;
+    pub use super::information::impl_base_aliases::*;
+    pub use super::length::impl_aliases::*;
+    pub use super::logarithmic::impl_aliases::*;
+    pub use super::power::impl_aliases::*;
+    pub use super::pressure::impl_aliases::*;
+    pub use super::time::impl_aliases::*;
+    pub use super::us_customary::impl_aliases::*;
+    pub use super::volume::impl_aliases::*;
     pub use super::weight::impl_aliases::*;
+    pub use super::__collect_catalogue::*;
 }
 
 cfg_if! {
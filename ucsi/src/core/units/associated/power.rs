@@ -0,0 +1,33 @@
+//! Power units.
+
+use crate::{
+    core::units::exported::power::Watt,
+    macros::unit_def::si_associated_unit_def,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Metric horsepower, exactly `75 kgf * m / s = 735.498 75 W`.
+    unit MetricHorsepower
+    based on Watt {
+        full_name: "Metric horsepower",
+        short_name: "metric horsepower",
+        unit_symbol: "PS",
+    }
+    alias PS;
+
+    /// Mechanical horsepower, exactly `550 ft * lbf / s`.
+    unit MechanicalHorsepower
+    based on Watt {
+        full_name: "Mechanical horsepower",
+        short_name: "mechanical horsepower",
+        unit_symbol: "hp",
+    }
+    alias hp;
+}
+
+rational_conversion! {
+    MetricHorsepower: 73_549_875 / 100_000;
+    MechanicalHorsepower: 37_284_993_579_113_511 / 50_000_000_000_000;
+}
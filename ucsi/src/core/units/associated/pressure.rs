@@ -0,0 +1,65 @@
+//! Pressure units.
+
+use crate::{
+    core::units::exported::pressure::Pascal,
+    macros::unit_def::si_associated_unit_def,
+};
+
+pub use super::imperial::PoundPerSquareInch;
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Bar, exactly `100 000 Pa`.
+    unit Bar
+    based on Pascal {
+        full_name: "Bar",
+        short_name: "bar",
+        unit_symbol: "bar",
+    }
+    alias bar;
+
+    /// Millibar, exactly `100 Pa`.
+    unit Millibar
+    based on Pascal {
+        full_name: "Millibar",
+        short_name: "millibar",
+        unit_symbol: "mbar",
+    }
+    alias mbar;
+
+    /// Standard atmosphere, exactly `101 325 Pa`.
+    unit Atmosphere
+    based on Pascal {
+        full_name: "Standard atmosphere",
+        short_name: "standard atmosphere",
+        unit_symbol: "atm",
+    }
+    alias atm;
+
+    /// Torr, exactly `101 325 / 760 Pa`.
+    unit Torr
+    based on Pascal {
+        full_name: "Torr",
+        short_name: "torr",
+        unit_symbol: "Torr",
+    }
+    alias Torr;
+
+    /// Conventional millimetre of mercury, exactly `133.322 387 415 Pa`.
+    unit MillimeterOfMercury
+    based on Pascal {
+        full_name: "Millimeter of mercury",
+        short_name: "millimeter of mercury",
+        unit_symbol: "mmHg",
+    }
+    alias mmHg;
+}
+
+rational_conversion! {
+    Bar: 100_000 / 1;
+    Millibar: 100 / 1;
+    Atmosphere: 101_325 / 1;
+    Torr: 20_265 / 152;
+    MillimeterOfMercury: 26_664_477_483 / 200_000_000;
+}
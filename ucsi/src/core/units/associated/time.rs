@@ -0,0 +1,38 @@
+use crate::{
+    core::units::base::Second,
+    macros::unit_def::si_associated_unit_def,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Minute
+    based on Second {
+        full_name: "Minute",
+        short_name: "minute",
+        unit_symbol: "min",
+    }
+    alias min;
+
+    unit Hour
+    based on Second {
+        full_name: "Hour",
+        short_name: "hour",
+        unit_symbol: "h",
+    }
+    alias h;
+
+    unit Day
+    based on Second {
+        full_name: "Day",
+        short_name: "day",
+        unit_symbol: "d",
+    }
+    alias d;
+}
+
+rational_conversion! {
+    Minute: 60 / 1;
+    Hour: 3_600 / 1;
+    Day: 86_400 / 1;
+}
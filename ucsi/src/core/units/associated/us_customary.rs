@@ -0,0 +1,58 @@
+//! US customary units.
+//!
+//! Lengths, masses, pressure, energy and temperature are shared with the [Imperial units](super::imperial).
+//! US liquid volumes are based on `1 gal = 231 in^3 = 3.785 411 784 L`,
+//! and are different types from the Imperial ones.
+
+use crate::{
+    core::units::base::Meter,
+    macros::unit_def::si_associated_unit_def,
+    unit,
+};
+
+pub use super::imperial::{
+    BritishThermalUnit, Fahrenheit, Foot, Inch, Mile, Ounce, Pound, PoundPerSquareInch, Yard,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit UsGallon
+    based on unit!(Meter ** { 3 }) {
+        full_name: "US gallon",
+        short_name: "US gallon",
+        unit_symbol: "US gal",
+    }
+    alias us_gal;
+
+    unit UsQuart
+    based on unit!(Meter ** { 3 }) {
+        full_name: "US quart",
+        short_name: "US quart",
+        unit_symbol: "US qt",
+    }
+    alias us_qt;
+
+    unit UsPint
+    based on unit!(Meter ** { 3 }) {
+        full_name: "US pint",
+        short_name: "US pint",
+        unit_symbol: "US pt",
+    }
+    alias us_pt;
+
+    unit UsFluidOunce
+    based on unit!(Meter ** { 3 }) {
+        full_name: "US fluid ounce",
+        short_name: "US fluid ounce",
+        unit_symbol: "US fl oz",
+    }
+    alias us_fl_oz;
+}
+
+rational_conversion! {
+    UsGallon: 3_785_411_784 / 1_000_000_000_000;
+    UsQuart: 946_352_946 / 1_000_000_000_000;
+    UsPint: 473_176_473 / 1_000_000_000_000;
+    UsFluidOunce: 295_735_295_625 / 10_000_000_000_000_000;
+}
@@ -0,0 +1,21 @@
+use crate::{
+    core::units::base::Meter,
+    macros::unit_def::si_associated_unit_def,
+    unit,
+};
+
+si_associated_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Litre
+    based on unit!(Meter ** { 3 }) {
+        full_name: "Litre",
+        short_name: "litre",
+        unit_symbol: "L",
+    }
+    alias L;
+}
+
+rational_conversion! {
+    Litre: 1 / 1_000;
+}
@@ -34,4 +34,33 @@ si_associated_unit_def! {
             from: |value| { value * 1000.0 }
         };
     };
+
+    unit Tonne
+    based on Kilogram {
+        full_name: "Tonne",
+        short_name: "tonne",
+        unit_symbol: "t",
+    }
+    alias t;
+
+    /// Dalton, or unified atomic mass unit, `m_u` in CODATA 2018.
+    unit Dalton
+    based on Kilogram {
+        full_name: "Dalton",
+        short_name: "dalton",
+        unit_symbol: "Da",
+    }
+    alias Da;
+}
+
+rational_conversion! {
+    Tonne: 1_000 / 1;
+}
+
+float_conversion! {
+    Dalton: crate::constants::codata2018::M_U.value;
+}
+
+exact_scale! {
+    Gram: 1 / 1_000;
 }
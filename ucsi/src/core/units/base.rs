@@ -4,6 +4,8 @@ use cfg_if::cfg_if;
 
 use crate::fraction::Fraction;
 
+use super::extra::ExtraDimensions;
+
 use super::any::{SiAnyUnit, SiDefinedUnit, SiDefinedUnitDefinition, SiDisplayableUnit, SiOpsUnit};
 
 cfg_if! {
@@ -117,14 +119,58 @@ __impl_si_base_unit_definition! {
     } alias cd;
 }
 
+cfg_if! {
+    if #[cfg(feature = "angle")] {
+        __impl_si_base_unit_definition! {
+            alias pub(crate) mod impl_angle_aliases;
+
+            unit Radian field radian {
+                full_name: "Radian",
+                short_name: "radian",
+                unit_symbol: "rad",
+            } alias rad;
+        }
+    } else {
+        /// # Radian
+        ///
+        /// The SI unit of plane angle.
+        ///
+        /// SI treats angles as dimensionless, so `Radian` has the same dimension as [`PureValue`]
+        /// unless the `angle` feature is enabled, which makes it a base unit of its own.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct Radian;
+
+        impl SiDefinedUnit for Radian {
+            const DEF: SiDefinedUnitDefinition = SiDefinedUnitDefinition {
+                full_name: "Radian",
+                short_name: "radian",
+                unit_symbol: "rad",
+            };
+        }
+
+        impl SiOpsUnit for Radian {
+            const UNIT_MAP: BaseUnitMap = BaseUnitMap::EMPTY;
+        }
+
+        impl SiAnyUnit for Radian {}
+
+        pub(crate) mod impl_angle_aliases {
+            #[allow(non_camel_case_types)]
+            pub type rad = super::Radian;
+        }
+    }
+}
+
 cfg_if! {
     if #[cfg(feature = "alias_hidden")] {
     } else if #[cfg(feature = "alias_mod")] {
         pub mod aliases {
             pub use super::impl_aliases::*;
+            pub use super::impl_angle_aliases::*;
         }
     } else if #[cfg(feature = "alias_export")] {
         pub use impl_aliases::*;
+        pub use impl_angle_aliases::*;
     }
 }
 
@@ -159,6 +205,7 @@ impl SiDisplayableUnit for PureValue {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "const_dim", derive(core::marker::ConstParamTy))]
 pub struct BaseUnitMap {
     pub meter: Fraction,
     pub second: Fraction,
@@ -167,6 +214,11 @@ pub struct BaseUnitMap {
     pub kelvins: Fraction,
     pub mole: Fraction,
     pub candela: Fraction,
+    /// Only available with the `angle` feature, see [`Radian`].
+    #[cfg(feature = "angle")]
+    pub radian: Fraction,
+    /// User-defined base dimensions, see [the `extra` module](super::extra).
+    pub extra: ExtraDimensions,
 }
 
 impl BaseUnitMap {
@@ -178,9 +230,15 @@ impl BaseUnitMap {
         kelvins: Fraction::ZERO,
         mole: Fraction::ZERO,
         candela: Fraction::ZERO,
+        #[cfg(feature = "angle")]
+        radian: Fraction::ZERO,
+        extra: ExtraDimensions::EMPTY,
     };
 
-    pub const fn unit_fields(&self) -> [(&'static str, &Fraction); 7] {
+    /// The count of base units, including the optional angle pseudo-dimension.
+    pub const FIELD_COUNT: usize = if cfg!(feature = "angle") { 8 } else { 7 };
+
+    pub const fn unit_fields(&self) -> [(&'static str, &Fraction); Self::FIELD_COUNT] {
         [
             (<Meter as SiBaseUnit>::DEF.unit_symbol, &self.meter),
             (<Second as SiBaseUnit>::DEF.unit_symbol, &self.second),
@@ -189,6 +247,8 @@ impl BaseUnitMap {
             (<Kelvins as SiBaseUnit>::DEF.unit_symbol, &self.kelvins),
             (<Mole as SiBaseUnit>::DEF.unit_symbol, &self.mole),
             (<Candela as SiBaseUnit>::DEF.unit_symbol, &self.candela),
+            #[cfg(feature = "angle")]
+            (<Radian as SiBaseUnit>::DEF.unit_symbol, &self.radian),
         ]
     }
 
@@ -202,10 +262,42 @@ impl BaseUnitMap {
             kelvins: self.kelvins.add(other.kelvins),
             mole: self.mole.add(other.mole),
             candela: self.candela.add(other.candela),
+            #[cfg(feature = "angle")]
+            radian: self.radian.add(other.radian),
+            extra: self.extra.add(other.extra),
         }
         .simplify()
     }
 
+    /// Add the exponents of `other`, `None` if an exponent overflows
+    /// or there are too many [extra dimensions](super::extra::ExtraDimensions::CAPACITY).
+    pub const fn checked_add(&self, other: Self) -> Option<Self> {
+        macro_rules! checked {
+            ($lhs:expr, $rhs:expr) => {
+                match $lhs.checked_add($rhs) {
+                    Some(exp) => exp,
+                    None => return None,
+                }
+            };
+        }
+
+        Some(
+            Self {
+                meter: checked!(self.meter, other.meter),
+                second: checked!(self.second, other.second),
+                kilogram: checked!(self.kilogram, other.kilogram),
+                ampere: checked!(self.ampere, other.ampere),
+                kelvins: checked!(self.kelvins, other.kelvins),
+                mole: checked!(self.mole, other.mole),
+                candela: checked!(self.candela, other.candela),
+                #[cfg(feature = "angle")]
+                radian: checked!(self.radian, other.radian),
+                extra: checked!(self.extra, other.extra),
+            }
+            .simplify(),
+        )
+    }
+
     #[inline]
     pub const fn simplify(mut self) -> Self {
         self.meter = self.meter.simplify();
@@ -215,6 +307,11 @@ impl BaseUnitMap {
         self.kelvins = self.kelvins.simplify();
         self.mole = self.mole.simplify();
         self.candela = self.candela.simplify();
+        #[cfg(feature = "angle")]
+        {
+            self.radian = self.radian.simplify();
+        }
+        self.extra = self.extra.simplify();
         self
     }
 
@@ -227,6 +324,11 @@ impl BaseUnitMap {
         self.kelvins = self.kelvins.neg();
         self.mole = self.mole.neg();
         self.candela = self.candela.neg();
+        #[cfg(feature = "angle")]
+        {
+            self.radian = self.radian.neg();
+        }
+        self.extra = self.extra.neg();
         self.simplify()
     }
 
@@ -240,10 +342,69 @@ impl BaseUnitMap {
             kelvins: self.kelvins.imul(rhs),
             mole: self.mole.imul(rhs),
             candela: self.candela.imul(rhs),
+            #[cfg(feature = "angle")]
+            radian: self.radian.imul(rhs),
+            extra: self.extra.imul(rhs),
         }
         .simplify()
     }
 
+    /// Multiply the exponents by an integer, `None` if an exponent overflows.
+    pub const fn checked_imul(&self, rhs: i32) -> Option<Self> {
+        macro_rules! checked {
+            ($exp:expr) => {
+                match $exp.checked_imul(rhs) {
+                    Some(exp) => exp,
+                    None => return None,
+                }
+            };
+        }
+
+        Some(
+            Self {
+                meter: checked!(self.meter),
+                second: checked!(self.second),
+                kilogram: checked!(self.kilogram),
+                ampere: checked!(self.ampere),
+                kelvins: checked!(self.kelvins),
+                mole: checked!(self.mole),
+                candela: checked!(self.candela),
+                #[cfg(feature = "angle")]
+                radian: checked!(self.radian),
+                extra: checked!(self.extra),
+            }
+            .simplify(),
+        )
+    }
+
+    /// Multiply the exponents by a fraction, `None` if an exponent overflows.
+    pub const fn checked_fmul(&self, f: Fraction) -> Option<Self> {
+        macro_rules! checked {
+            ($exp:expr) => {
+                match $exp.checked_fmul(f) {
+                    Some(exp) => exp,
+                    None => return None,
+                }
+            };
+        }
+
+        Some(
+            Self {
+                meter: checked!(self.meter),
+                second: checked!(self.second),
+                kilogram: checked!(self.kilogram),
+                ampere: checked!(self.ampere),
+                kelvins: checked!(self.kelvins),
+                mole: checked!(self.mole),
+                candela: checked!(self.candela),
+                #[cfg(feature = "angle")]
+                radian: checked!(self.radian),
+                extra: checked!(self.extra),
+            }
+            .simplify(),
+        )
+    }
+
     #[inline]
     pub const fn fmul(&self, f: Fraction) -> Self {
         Self {
@@ -254,6 +415,9 @@ impl BaseUnitMap {
             kelvins: self.kelvins.fmul(f),
             mole: self.mole.fmul(f),
             candela: self.candela.fmul(f),
+            #[cfg(feature = "angle")]
+            radian: self.radian.fmul(f),
+            extra: self.extra.fmul(f),
         }
         .simplify()
     }
@@ -267,5 +431,24 @@ impl BaseUnitMap {
             && self.kelvins.eq(&rhs.kelvins)
             && self.mole.eq(&rhs.mole)
             && self.candela.eq(&rhs.candela)
+            && self.angle_eq(rhs)
+            && self.extra.eq(&rhs.extra)
+    }
+
+    /// Iterate over all fields, including the [extra dimensions](super::extra).
+    pub fn all_fields(&self) -> impl Iterator<Item = (&'static str, &Fraction)> + '_ {
+        self.unit_fields().into_iter().chain(self.extra.iter())
+    }
+
+    #[cfg(feature = "angle")]
+    #[inline]
+    const fn angle_eq(&self, rhs: &Self) -> bool {
+        self.radian.eq(&rhs.radian)
+    }
+
+    #[cfg(not(feature = "angle"))]
+    #[inline]
+    const fn angle_eq(&self, _rhs: &Self) -> bool {
+        true
     }
 }
@@ -0,0 +1,374 @@
+//! # The `canonical` module
+//!
+//! Units built with the [`unit`](crate::unit) macro are structural:
+//! `unit!(m * s)` and `unit!(s * m)` share the same [`BaseUnitMap`],
+//! but they are two different rust types.
+//! This makes it impossible to name "the" unit of a dimension in trait impls,
+//! struct fields or function signatures.
+//!
+//! This module offers a canonical representation, [`Dim`],
+//! which stores the exponent of every base unit as a
+//! [`typenum`](https://docs.rs/typenum) integer.
+//! Every unit implementing [`Canonicalize`] can be normalized with the [`Canonical`] alias,
+//! and units with the same dimension always yield the same type.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::base::{m, s};
+//! use ucsi::units::canonical::Canonical;
+//! use ucsi::unit;
+//! use ucsi::Value;
+//!
+//! fn same<T>(_: T, _: T) {}
+//!
+//! let a: Value<f64, Canonical<unit!(m * s)>> = Value::new(1.0);
+//! let b: Value<f64, Canonical<unit!(s * m)>> = Value::new(1.0);
+//! same(a, b);
+//!
+//! // arithmetic can produce canonical units directly
+//! let speed = Value::<f64, m>::new(10.0).div_canonical(Value::<f64, s>::new(2.0));
+//! let _: Value<f64, Canonical<unit!(m / s)>> = speed;
+//! ```
+//!
+//! The canonical units are coherent, i.e. their scale factor is `1`,
+//! so scaled operands are rejected at compile time. Use `rescale` first:
+//!
+//! ```rust,compile_fail
+//! use ucsi::ops::Scaled;
+//! use ucsi::units::base::{m, s};
+//! use ucsi::Value;
+//!
+//! let km = Value::<f64, Scaled<m, 1000, 1>>::new(1.0);
+//! let _ = km.mul_canonical(Value::<f64, s>::new(1.0));
+//! ```
+//!
+//! ## Restrictions
+//!
+//! Type-level exponents are integers,
+//! so units with fractional exponents (e.g. [`PowFrac`](crate::ops::PowFrac))
+//! cannot be canonicalized.
+//! [`PowI`] is supported for exponents within `-16..=16`.
+//!
+//! ## Related features
+//!
+//! - `canonical`: Enable this module.
+//!   (Disabled by default. Included in the `full` feature.)
+
+use core::{fmt, marker::PhantomData, ops};
+
+use typenum::{consts::*, Diff, Integer, Prod, Sum};
+
+use crate::{
+    __dbg_assert,
+    fraction::Fraction,
+    ops::{Div, Mul, PowI},
+    units::{
+        any::{CastFrom, SiAnyUnit, SiDisplayableUnit, SiOpsUnit},
+        base::{Ampere, BaseUnitMap, Candela, Kelvins, Kilogram, Meter, Mole, PureValue, Second},
+    },
+    Value,
+};
+
+/// Canonical unit type.
+///
+/// Each parameter is the [`typenum`] integer exponent of a base unit,
+/// in the order of the [`BaseUnitMap`] fields:
+/// meter, second, kilogram, ampere, kelvins, mole and candela.
+///
+/// This is not recommended to write the definition manually.
+/// Use the [`Canonical`] alias instead.
+pub struct Dim<M, S, KG, A, K, MOL, CD> {
+    _p: PhantomData<(M, S, KG, A, K, MOL, CD)>,
+}
+
+impl<M, S, KG, A, K, MOL, CD> Dim<M, S, KG, A, K, MOL, CD> {
+    /// Create an instance of the `Dim` type.
+    pub const fn new() -> Self {
+        Dim { _p: PhantomData }
+    }
+}
+
+impl<M, S, KG, A, K, MOL, CD> Default for Dim<M, S, KG, A, K, MOL, CD> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, S, KG, A, K, MOL, CD> SiOpsUnit for Dim<M, S, KG, A, K, MOL, CD>
+where
+    M: Integer,
+    S: Integer,
+    KG: Integer,
+    A: Integer,
+    K: Integer,
+    MOL: Integer,
+    CD: Integer,
+{
+    const UNIT_MAP: BaseUnitMap = BaseUnitMap {
+        meter: Fraction::new(M::I32, 1),
+        second: Fraction::new(S::I32, 1),
+        kilogram: Fraction::new(KG::I32, 1),
+        ampere: Fraction::new(A::I32, 1),
+        kelvins: Fraction::new(K::I32, 1),
+        mole: Fraction::new(MOL::I32, 1),
+        candela: Fraction::new(CD::I32, 1),
+        ..BaseUnitMap::EMPTY
+    };
+}
+
+impl<M, S, KG, A, K, MOL, CD> SiAnyUnit for Dim<M, S, KG, A, K, MOL, CD> {}
+
+impl<M, S, KG, A, K, MOL, CD> SiDisplayableUnit for Dim<M, S, KG, A, K, MOL, CD>
+where
+    Self: SiOpsUnit,
+{
+    const DISPLAYABLE: bool = !Self::UNIT_MAP.eq(&BaseUnitMap::EMPTY);
+
+    fn display_symbol(w: &mut impl fmt::Write) -> fmt::Result {
+        let mut first = true;
+        for (n, u) in Self::UNIT_MAP.unit_fields() {
+            if u.is_zero() {
+                continue;
+            }
+            if !first {
+                write!(w, " * ")?;
+            }
+            first = false;
+            if u.eq(&Fraction::ONE) {
+                write!(w, "{}", n)?;
+            } else {
+                write!(w, "{} ** {{{}}}", n, u)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<M1, S1, KG1, A1, K1, MOL1, CD1, M2, S2, KG2, A2, K2, MOL2, CD2>
+    ops::Add<Dim<M2, S2, KG2, A2, K2, MOL2, CD2>> for Dim<M1, S1, KG1, A1, K1, MOL1, CD1>
+where
+    M1: ops::Add<M2>,
+    S1: ops::Add<S2>,
+    KG1: ops::Add<KG2>,
+    A1: ops::Add<A2>,
+    K1: ops::Add<K2>,
+    MOL1: ops::Add<MOL2>,
+    CD1: ops::Add<CD2>,
+{
+    type Output = Dim<
+        Sum<M1, M2>,
+        Sum<S1, S2>,
+        Sum<KG1, KG2>,
+        Sum<A1, A2>,
+        Sum<K1, K2>,
+        Sum<MOL1, MOL2>,
+        Sum<CD1, CD2>,
+    >;
+
+    fn add(self, _: Dim<M2, S2, KG2, A2, K2, MOL2, CD2>) -> Self::Output {
+        Dim::new()
+    }
+}
+
+impl<M1, S1, KG1, A1, K1, MOL1, CD1, M2, S2, KG2, A2, K2, MOL2, CD2>
+    ops::Sub<Dim<M2, S2, KG2, A2, K2, MOL2, CD2>> for Dim<M1, S1, KG1, A1, K1, MOL1, CD1>
+where
+    M1: ops::Sub<M2>,
+    S1: ops::Sub<S2>,
+    KG1: ops::Sub<KG2>,
+    A1: ops::Sub<A2>,
+    K1: ops::Sub<K2>,
+    MOL1: ops::Sub<MOL2>,
+    CD1: ops::Sub<CD2>,
+{
+    type Output = Dim<
+        Diff<M1, M2>,
+        Diff<S1, S2>,
+        Diff<KG1, KG2>,
+        Diff<A1, A2>,
+        Diff<K1, K2>,
+        Diff<MOL1, MOL2>,
+        Diff<CD1, CD2>,
+    >;
+
+    fn sub(self, _: Dim<M2, S2, KG2, A2, K2, MOL2, CD2>) -> Self::Output {
+        Dim::new()
+    }
+}
+
+impl<M, S, KG, A, K, MOL, CD, P: Integer> ops::Mul<P> for Dim<M, S, KG, A, K, MOL, CD>
+where
+    M: ops::Mul<P>,
+    S: ops::Mul<P>,
+    KG: ops::Mul<P>,
+    A: ops::Mul<P>,
+    K: ops::Mul<P>,
+    MOL: ops::Mul<P>,
+    CD: ops::Mul<P>,
+{
+    type Output = Dim<
+        Prod<M, P>,
+        Prod<S, P>,
+        Prod<KG, P>,
+        Prod<A, P>,
+        Prod<K, P>,
+        Prod<MOL, P>,
+        Prod<CD, P>,
+    >;
+
+    fn mul(self, _: P) -> Self::Output {
+        Dim::new()
+    }
+}
+
+/// Units that can be normalized into a [`Dim`].
+pub trait Canonicalize: SiOpsUnit {
+    /// The canonical unit, which has the same [`BaseUnitMap`] as `Self`.
+    type Canonical: SiOpsUnit;
+}
+
+/// The canonical unit type of `U`.
+///
+/// Units with the same dimension always yield the same type.
+pub type Canonical<U> = <U as Canonicalize>::Canonical;
+
+impl<M, S, KG, A, K, MOL, CD> Canonicalize for Dim<M, S, KG, A, K, MOL, CD>
+where
+    Self: SiOpsUnit,
+{
+    type Canonical = Self;
+}
+
+impl Canonicalize for PureValue {
+    type Canonical = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+}
+
+impl Canonicalize for Meter {
+    type Canonical = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+}
+
+impl Canonicalize for Second {
+    type Canonical = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+}
+
+impl Canonicalize for Kilogram {
+    type Canonical = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+}
+
+impl Canonicalize for Ampere {
+    type Canonical = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+}
+
+impl Canonicalize for Kelvins {
+    type Canonical = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+}
+
+impl Canonicalize for Mole {
+    type Canonical = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+}
+
+impl Canonicalize for Candela {
+    type Canonical = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+}
+
+/// With the `angle` feature, radian is a base unit which [`Dim`] cannot represent,
+/// so angle units are not [`Canonicalize`].
+#[cfg(not(feature = "angle"))]
+impl Canonicalize for super::base::Radian {
+    type Canonical = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+}
+
+impl<L: Canonicalize, R: Canonicalize> Canonicalize for Mul<L, R>
+where
+    L::Canonical: ops::Add<R::Canonical>,
+    Sum<L::Canonical, R::Canonical>: SiOpsUnit,
+{
+    type Canonical = Sum<L::Canonical, R::Canonical>;
+}
+
+impl<N: Canonicalize, D: Canonicalize> Canonicalize for Div<N, D>
+where
+    N::Canonical: ops::Sub<D::Canonical>,
+    Diff<N::Canonical, D::Canonical>: SiOpsUnit,
+{
+    type Canonical = Diff<N::Canonical, D::Canonical>;
+}
+
+impl<B: Canonicalize, const P: i32> Canonicalize for PowI<B, P>
+where
+    ConstI32<P>: ToInteger,
+    B::Canonical: ops::Mul<<ConstI32<P> as ToInteger>::Output>,
+    Prod<B::Canonical, <ConstI32<P> as ToInteger>::Output>: SiOpsUnit,
+{
+    type Canonical = Prod<B::Canonical, <ConstI32<P> as ToInteger>::Output>;
+}
+
+/// Const integer wrapper, used to map a const generic to a [`typenum`] integer.
+pub struct ConstI32<const N: i32>;
+
+/// Map a const generic to a [`typenum`] integer.
+pub trait ToInteger {
+    type Output: Integer;
+}
+
+macro_rules! __impl_to_integer {
+    ($($n:literal => $ty:ty),+ $(,)?) => {
+        $(
+            impl ToInteger for ConstI32<$n> {
+                type Output = $ty;
+            }
+        )+
+    };
+}
+
+__impl_to_integer! {
+    -16 => N16, -15 => N15, -14 => N14, -13 => N13, -12 => N12, -11 => N11,
+    -10 => N10, -9 => N9, -8 => N8, -7 => N7, -6 => N6, -5 => N5,
+    -4 => N4, -3 => N3, -2 => N2, -1 => N1, 0 => Z0, 1 => P1,
+    2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7,
+    8 => P8, 9 => P9, 10 => P10, 11 => P11, 12 => P12, 13 => P13,
+    14 => P14, 15 => P15, 16 => P16,
+}
+
+impl<T: Canonicalize, V> Value<V, T> {
+    /// Cast the value into its [`Canonical`] unit.
+    #[inline]
+    pub fn canonicalize(self) -> Value<V, Canonical<T>> {
+        __dbg_assert!(<Canonical<T> as CastFrom<T>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+
+    /// Multiply two values and produce the [`Canonical`] unit directly.
+    #[inline]
+    pub fn mul_canonical<L, B>(self, rhs: Value<L, B>) -> Value<V::Output, Canonical<Mul<T, B>>>
+    where
+        V: ops::Mul<L>,
+        B: Canonicalize,
+        Mul<T, B>: Canonicalize,
+    {
+        __dbg_assert!(<Canonical<Mul<T, B>> as CastFrom<Mul<T, B>>>::CAN_CAST_FROM);
+        Value::new(self.value.mul(rhs.value))
+    }
+
+    /// Divide two values and produce the [`Canonical`] unit directly.
+    #[inline]
+    pub fn div_canonical<L, B>(self, rhs: Value<L, B>) -> Value<V::Output, Canonical<Div<T, B>>>
+    where
+        V: ops::Div<L>,
+        B: Canonicalize,
+        Div<T, B>: Canonicalize,
+    {
+        __dbg_assert!(<Canonical<Div<T, B>> as CastFrom<Div<T, B>>>::CAN_CAST_FROM);
+        Value::new(self.value.div(rhs.value))
+    }
+}
+
+impl<T: Canonicalize, V: Copy> Value<V, T> {
+    /// Cast the value into its [`Canonical`] unit.
+    #[inline]
+    pub const fn canonicalize_const(self) -> Value<V, Canonical<T>> {
+        __dbg_assert!(<Canonical<T> as CastFrom<T>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+}
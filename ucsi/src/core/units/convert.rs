@@ -0,0 +1,396 @@
+//! # The `convert` module
+//!
+//! Conversion between units only known at runtime, e.g. in user-facing tools.
+//!
+//! Unit expressions are parsed against a [registry](super::registry) with the following syntax:
+//!
+//! - Unit symbols (`m`, `mi`, `kWh`), or names (`hour`, `Foot`).
+//!   The coherent SI units, `g`, `L` and `eV` accept SI prefixes, e.g. `km`, `ms` or `MeV`.
+//!   Micro is written `µ`, `μ` or `u`.
+//! - Finite non-zero numbers, e.g. the `1` in `1/s`.
+//! - `*` or `·` for multiplication, `/` for division, left associative:
+//!   `J/kg*K` is `(J/kg)*K`.
+//!   Symbols separated by spaces, e.g. `N m`, are multiplied
+//!   unless the whole text is a symbol, e.g. `imp gal`.
+//! - `^` or `**` with an integer exponent, e.g. `m^2`, `s^-1` or `s**(-2)`.
+//! - Parentheses.
+//!
+//! Affine units, i.e. temperatures like `°F`, only convert with their offset
+//! when they form the whole expression.
+//! Inside compound expressions, e.g. `°F/s`, they are temperature differences.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::core::units::convert::{convert, ConvertError};
+//!
+//! let speed = convert(12.0, "mi/h", "m/s").unwrap();
+//! assert!((speed - 5.364_48).abs() < 1e-12);
+//!
+//! let pressure = convert(1.0, "atm", "kPa").unwrap();
+//! assert!((pressure - 101.325).abs() < 1e-12);
+//!
+//! let temperature = convert(212.0, "°F", "K").unwrap();
+//! assert!((temperature - 373.15).abs() < 1e-12);
+//!
+//! assert!(matches!(convert(1.0, "mi", "s"), Err(ConvertError::DimensionMismatch { .. })));
+//! assert!(matches!(convert(1.0, "furlong", "m"), Err(ConvertError::UnknownUnit(_))));
+//! ```
+//!
+//! ## Related features
+//!
+//! - `use_alloc`: Enable this module.
+
+extern crate alloc;
+use alloc::string::{String, ToString};
+use core::{fmt, ops};
+
+use super::{
+    base::BaseUnitMap,
+    registry::{find_by_name, find_by_symbol, Registry, UnitEntry, BUILTIN_UNITS},
+    scale::Scale,
+    system::powi,
+};
+use crate::core::dyn_value::DynValue;
+
+/// Errors of unit parsing and conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvertError {
+    /// No unit with this symbol or name.
+    UnknownUnit(String),
+    /// A number in the expression is zero, infinite or NaN.
+    InvalidFactor(String),
+    /// Invalid expression, `position` is the byte offset in the expression.
+    Syntax {
+        position: usize,
+        message: &'static str,
+    },
+    /// The two expressions have different dimensions.
+    DimensionMismatch { from: String, to: String },
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::UnknownUnit(unit) => write!(f, "unknown unit `{}`", unit),
+            ConvertError::InvalidFactor(number) => {
+                write!(f, "invalid factor `{}`, expected a finite non-zero number", number)
+            }
+            ConvertError::Syntax { position, message } => {
+                write!(f, "syntax error at {}: {}", position, message)
+            }
+            ConvertError::DimensionMismatch { from, to } => {
+                write!(
+                    f,
+                    "cannot convert `{}` to `{}`: dimension mismatch",
+                    from, to
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for ConvertError {}
+
+/// A parsed unit expression.
+///
+/// A value `x` of the expression is `x * factor + offset` in the coherent unit of `unit_map`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitExpr {
+    pub unit_map: BaseUnitMap,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+impl UnitExpr {
+    /// The dimensionless unit `1`.
+    pub const ONE: UnitExpr = UnitExpr {
+        unit_map: BaseUnitMap::EMPTY,
+        factor: 1.0,
+        offset: 0.0,
+    };
+
+    const fn entry(entry: &UnitEntry) -> UnitExpr {
+        UnitExpr {
+            unit_map: entry.unit_map,
+            factor: entry.factor,
+            offset: entry.offset,
+        }
+    }
+
+    /// Raise to an integer power, `None` if an exponent or the factor overflows.
+    pub fn powi(self, exp: i32) -> Option<UnitExpr> {
+        let unit_map = self.unit_map.checked_imul(exp)?;
+        let factor = powi(self.factor, exp);
+        if !factor.is_finite() || factor == 0.0 {
+            return None;
+        }
+        Some(UnitExpr {
+            unit_map,
+            factor,
+            offset: if exp == 1 { self.offset } else { 0.0 },
+        })
+    }
+
+    /// Convert a value of the expression to the coherent unit.
+    #[inline]
+    pub fn to_coherent(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    /// Convert a value of the coherent unit to the expression.
+    #[inline]
+    pub fn from_coherent(&self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+
+    /// A [`DynValue`] in the coherent unit.
+    #[inline]
+    pub fn to_dyn(&self, value: f64) -> DynValue<f64> {
+        DynValue::new(self.to_coherent(value), self.unit_map)
+    }
+}
+
+impl ops::Mul for UnitExpr {
+    type Output = UnitExpr;
+
+    #[inline]
+    fn mul(self, rhs: UnitExpr) -> UnitExpr {
+        UnitExpr {
+            unit_map: self.unit_map.add(rhs.unit_map).simplify(),
+            factor: self.factor * rhs.factor,
+            offset: 0.0,
+        }
+    }
+}
+
+impl ops::Div for UnitExpr {
+    type Output = UnitExpr;
+
+    #[inline]
+    fn div(self, rhs: UnitExpr) -> UnitExpr {
+        UnitExpr {
+            unit_map: self.unit_map.add(rhs.unit_map.neg()).simplify(),
+            factor: self.factor / rhs.factor,
+            offset: 0.0,
+        }
+    }
+}
+
+const PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("µ", 1e-6),
+    ("μ", 1e-6),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+];
+
+fn is_prefixable(entry: &UnitEntry) -> bool {
+    matches!(entry.unit_symbol, "g" | "L" | "eV")
+        || (entry.scale == Some(Scale::ONE) && entry.unit_symbol != "kg")
+}
+
+fn resolve(units: &[UnitEntry], atom: &str) -> Result<UnitExpr, ConvertError> {
+    if let Some(entry) = find_by_symbol(units, atom).or_else(|| find_by_name(units, atom)) {
+        return Ok(UnitExpr::entry(entry));
+    }
+
+    if atom.contains(char::is_whitespace) {
+        return atom
+            .split_whitespace()
+            .try_fold(UnitExpr::ONE, |acc, part| Ok(acc * resolve(units, part)?));
+    }
+
+    if let Ok(number) = atom.parse::<f64>() {
+        if !number.is_finite() || number == 0.0 {
+            return Err(ConvertError::InvalidFactor(atom.to_string()));
+        }
+        return Ok(UnitExpr {
+            factor: number,
+            ..UnitExpr::ONE
+        });
+    }
+
+    for (prefix, factor) in PREFIXES {
+        if let Some(symbol) = atom.strip_prefix(prefix) {
+            if let Some(entry) = find_by_symbol(units, symbol).filter(|e| is_prefixable(e)) {
+                return Ok(UnitExpr {
+                    factor: factor * entry.factor,
+                    ..UnitExpr::entry(entry)
+                });
+            }
+        }
+    }
+
+    Err(ConvertError::UnknownUnit(atom.to_string()))
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    units: &'a [UnitEntry],
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error<T>(&self, message: &'static str) -> Result<T, ConvertError> {
+        Err(ConvertError::Syntax {
+            position: self.pos,
+            message,
+        })
+    }
+
+    fn expr(&mut self) -> Result<UnitExpr, ConvertError> {
+        let mut lhs = self.term()?;
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("**") {
+                return self.error("unexpected `**`");
+            } else if self.eat("*") || self.eat("·") {
+                lhs = lhs * self.term()?;
+            } else if self.eat("/") {
+                lhs = lhs / self.term()?;
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<UnitExpr, ConvertError> {
+        let base = self.factor()?;
+        if self.eat("^") || self.eat("**") {
+            self.skip_whitespace();
+            let position = self.pos;
+            base.powi(self.exponent()?).ok_or(ConvertError::Syntax {
+                position,
+                message: "exponent overflow",
+            })
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn exponent(&mut self) -> Result<i32, ConvertError> {
+        let parenthesized = self.eat("(");
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(rest.len(), |(i, _)| i);
+        let Ok(exp) = rest[..len].parse::<i32>() else {
+            return self.error("expected an integer exponent");
+        };
+        self.pos += len;
+        if parenthesized && !self.eat(")") {
+            return self.error("expected `)`");
+        }
+        Ok(exp)
+    }
+
+    fn factor(&mut self) -> Result<UnitExpr, ConvertError> {
+        if self.eat("(") {
+            let inner = self.expr()?;
+            if !self.eat(")") {
+                return self.error("expected `)`");
+            }
+            return Ok(inner);
+        }
+
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(['*', '/', '·', '^', '(', ')'])
+            .unwrap_or(rest.len());
+        let atom = rest[..len].trim_end();
+        if atom.is_empty() {
+            return self.error("expected a unit");
+        }
+        self.pos += len;
+        resolve(self.units, atom)
+    }
+}
+
+/// Parse a unit expression against `units`.
+pub fn parse_unit(units: &[UnitEntry], expr: &str) -> Result<UnitExpr, ConvertError> {
+    let mut parser = Parser {
+        src: expr,
+        pos: 0,
+        units,
+    };
+    let unit = parser.expr()?;
+    parser.skip_whitespace();
+    if parser.pos < expr.len() {
+        return parser.error("unexpected input");
+    }
+    Ok(unit)
+}
+
+/// Convert `value` from the unit expression `from` to `to`, both parsed against `units`.
+pub fn convert_with(
+    units: &[UnitEntry],
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConvertError> {
+    let lhs = parse_unit(units, from)?;
+    let rhs = parse_unit(units, to)?;
+    if !lhs.unit_map.eq(&rhs.unit_map) {
+        return Err(ConvertError::DimensionMismatch {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+    }
+    Ok(rhs.from_coherent(lhs.to_coherent(value)))
+}
+
+/// Convert `value` from the unit expression `from` to `to`, using the [built-in units](BUILTIN_UNITS).
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, ConvertError> {
+    convert_with(BUILTIN_UNITS, value, from, to)
+}
+
+impl Registry {
+    /// Parse a unit expression, see [the `convert` module](self).
+    pub fn parse(&self, expr: &str) -> Result<UnitExpr, ConvertError> {
+        parse_unit(self.entries(), expr)
+    }
+
+    /// Convert `value` between two unit expressions, see [the `convert` module](self).
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, ConvertError> {
+        convert_with(self.entries(), value, from, to)
+    }
+}
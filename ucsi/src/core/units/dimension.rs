@@ -0,0 +1,194 @@
+//! # The `dimension` module
+//!
+//! By default, unit checks are done by const evaluation of the [`BaseUnitMap`][map]
+//! in [`CastFrom`](crate::units::any::CastFrom).
+//! Const panics only fire on monomorphization,
+//! and a plain trait bound cannot express "same dimension".
+//!
+//! This module offers a type-level backend based on the [`canonical`](super::canonical) module.
+//! Every [`Canonicalize`] unit exposes the [`typenum`] exponent of each base unit
+//! through the [`Dimension`] trait,
+//! so [`SameDimension`] is a real trait bound verified by the type checker.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::dimension::SameDimension;
+//! use ucsi::units::exported::force::Newton;
+//! use ucsi::unit;
+//! use ucsi::Value;
+//!
+//! fn to_newton<U: SameDimension<Newton>>(v: Value<f64, U>) -> Value<f64, Newton> {
+//!     v.cast_dim()
+//! }
+//!
+//! let force: Value<f64, unit!(kg * (m / (s ** { 2 })))> = Value::new(1.0);
+//! let _ = to_newton(force);
+//! ```
+//!
+//! Mismatching units are reported as unsatisfied trait bounds
+//! instead of const-evaluation panics:
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::base::{kg, m, s};
+//! # use ucsi::units::dimension::SameDimension;
+//! # use ucsi::units::exported::force::Newton;
+//! # use ucsi::Value;
+//! # fn to_newton<U: SameDimension<Newton>>(v: Value<f64, U>) -> Value<f64, Newton> {
+//! #     v.cast_dim()
+//! # }
+//! let mass: Value<f64, kg> = Value::new(1.0);
+//! let _ = to_newton(mass);
+//! ```
+//!
+//! The trait bound only covers the dimension.
+//! Like [`CastFrom`](crate::units::any::CastFrom), the methods of this module also check
+//! the [kinds](crate::units::kind) and the [scale factors](crate::units::any::SiOpsUnit::SCALE)
+//! by const evaluation, so torque cannot be cast to energy:
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::exported::{energy::Joule, torque::NewtonMeter};
+//! # use ucsi::Value;
+//! let _ = Value::<f64, NewtonMeter>::new(5.0).cast_dim::<Joule>();
+//! ```
+//!
+//! ## Related features
+//!
+//! - `type_dimension`: Enable this module. Implies the `canonical` feature.
+//!   (Disabled by default. Included in the `full` feature.)
+//!
+//! [map]: crate::units::base::BaseUnitMap
+
+use core::ops;
+
+use typenum::Integer;
+
+use crate::{
+    __dbg_assert,
+    units::{
+        any::CastFrom,
+        canonical::{Canonical, Canonicalize, Dim},
+    },
+    Value,
+};
+
+/// Type-level exponents of a [`Dim`].
+pub trait DimExponents {
+    type Meter: Integer;
+    type Second: Integer;
+    type Kilogram: Integer;
+    type Ampere: Integer;
+    type Kelvins: Integer;
+    type Mole: Integer;
+    type Candela: Integer;
+}
+
+impl<M, S, KG, A, K, MOL, CD> DimExponents for Dim<M, S, KG, A, K, MOL, CD>
+where
+    M: Integer,
+    S: Integer,
+    KG: Integer,
+    A: Integer,
+    K: Integer,
+    MOL: Integer,
+    CD: Integer,
+{
+    type Meter = M;
+    type Second = S;
+    type Kilogram = KG;
+    type Ampere = A;
+    type Kelvins = K;
+    type Mole = MOL;
+    type Candela = CD;
+}
+
+/// Units exposing the type-level exponent of each base unit.
+///
+/// This is implemented for every [`Canonicalize`] unit.
+pub trait Dimension: Canonicalize {
+    type Meter: Integer;
+    type Second: Integer;
+    type Kilogram: Integer;
+    type Ampere: Integer;
+    type Kelvins: Integer;
+    type Mole: Integer;
+    type Candela: Integer;
+}
+
+impl<T: Canonicalize> Dimension for T
+where
+    Canonical<T>: DimExponents,
+{
+    type Meter = <Canonical<T> as DimExponents>::Meter;
+    type Second = <Canonical<T> as DimExponents>::Second;
+    type Kilogram = <Canonical<T> as DimExponents>::Kilogram;
+    type Ampere = <Canonical<T> as DimExponents>::Ampere;
+    type Kelvins = <Canonical<T> as DimExponents>::Kelvins;
+    type Mole = <Canonical<T> as DimExponents>::Mole;
+    type Candela = <Canonical<T> as DimExponents>::Candela;
+}
+
+/// Units with the same dimension as `U`.
+///
+/// Unlike [`CastFrom`](crate::units::any::CastFrom),
+/// this is checked by the type checker rather than by const evaluation.
+pub trait SameDimension<U: Dimension>: Dimension {}
+
+impl<T: Dimension, U: Dimension<Canonical = Canonical<T>>> SameDimension<U> for T {}
+
+impl<T: Dimension, V> Value<V, T> {
+    /// Cast the value to a unit with the same dimension.
+    ///
+    /// The dimension is checked with the [`SameDimension`] trait bound,
+    /// the kind and the scale factor like [`CastFrom`].
+    #[inline]
+    pub fn cast_dim<B: Dimension>(self) -> Value<V, B>
+    where
+        T: SameDimension<B>,
+    {
+        __dbg_assert!(<B as CastFrom<T>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+
+    /// Add a value with the same dimension, kind and scale factor.
+    ///
+    /// The result has the left-hand unit.
+    #[inline]
+    pub fn add_dim<L, B>(self, rhs: Value<L, B>) -> Value<V::Output, T>
+    where
+        B: SameDimension<T>,
+        V: ops::Add<L>,
+    {
+        __dbg_assert!(<T as CastFrom<B>>::CAN_CAST_FROM);
+        Value::new(self.value.add(rhs.value))
+    }
+
+    /// Subtract a value with the same dimension, kind and scale factor.
+    ///
+    /// The result has the left-hand unit.
+    #[inline]
+    pub fn sub_dim<L, B>(self, rhs: Value<L, B>) -> Value<V::Output, T>
+    where
+        B: SameDimension<T>,
+        V: ops::Sub<L>,
+    {
+        __dbg_assert!(<T as CastFrom<B>>::CAN_CAST_FROM);
+        Value::new(self.value.sub(rhs.value))
+    }
+}
+
+impl<T: Dimension, V: Copy> Value<V, T> {
+    /// Cast the value to a unit with the same dimension.
+    ///
+    /// The dimension is checked with the [`SameDimension`] trait bound,
+    /// the kind and the scale factor like [`CastFrom`].
+    #[inline]
+    pub const fn cast_dim_const<B: Dimension>(self) -> Value<V, B>
+    where
+        T: SameDimension<B>,
+    {
+        __dbg_assert!(<B as CastFrom<T>>::CAN_CAST_FROM);
+        Value::new(self.value)
+    }
+}
@@ -0,0 +1,24 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::{base::Second, kind},
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Hertz based on unit!(Second ** { -1 }) {
+        full_name: "Hertz",
+        short_name: "hertz",
+        unit_symbol: "Hz",
+        kind: Some(kind::FREQUENCY),
+    } alias Hz;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
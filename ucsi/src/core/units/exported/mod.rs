@@ -3,7 +3,7 @@ use cfg_if::cfg_if;
 use crate::{macros::unit_def::si_exported_unit_def, unit};
 
 use super::{
-    any::{SiAnyUnit, SiDefinedUnit, SiOpsUnit},
+    any::{QuantityKind, SiAnyUnit, SiDefinedUnit, SiOpsUnit},
     base::{Kilogram, Meter, Second},
 };
 
@@ -12,6 +12,8 @@ pub struct SiExportedUnitDefinition {
     pub full_name: &'static str,
     pub short_name: &'static str,
     pub unit_symbol: &'static str,
+    /// Optional kind of quantity, see [`SiOpsUnit::KIND`].
+    pub kind: Option<QuantityKind>,
 }
 
 pub trait SiExportedUnit: SiDefinedUnit + SiAnyUnit {
@@ -19,7 +21,25 @@ pub trait SiExportedUnit: SiDefinedUnit + SiAnyUnit {
     const DEF: SiExportedUnitDefinition;
 }
 
+pub mod energy;
 pub mod force;
+pub mod frequency;
+pub mod power;
+pub mod pressure;
+pub mod radiation;
+pub mod torque;
+pub mod voltage;
+
+mod __collect_impl {
+    pub use super::energy::impl_aliases::*;
+    pub use super::force::impl_aliases::*;
+    pub use super::frequency::impl_aliases::*;
+    pub use super::power::impl_aliases::*;
+    pub use super::pressure::impl_aliases::*;
+    pub use super::radiation::impl_aliases::*;
+    pub use super::torque::impl_aliases::*;
+    pub use super::voltage::impl_aliases::*;
+}
 
 cfg_if! {
     if #[cfg(feature = "alias_hidden")] {
@@ -27,5 +47,7 @@ cfg_if! {
         pub mod aliases {
             pub use super::__collect_impl::*;
         }
+    } else if #[cfg(feature = "alias_export")] {
+        pub use __collect_impl::*;
     }
 }
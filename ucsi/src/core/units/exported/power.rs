@@ -0,0 +1,23 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::base::{Kilogram, Meter, Second},
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Watt based on unit!((Kilogram * (Meter ** { 2 })) / (Second ** { 3 })) {
+        full_name: "Watt",
+        short_name: "watt",
+        unit_symbol: "W",
+    } alias W;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
@@ -0,0 +1,23 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::base::{Kilogram, Meter, Second},
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Pascal based on unit!(Kilogram / (Meter * (Second ** { 2 }))) {
+        full_name: "Pascal",
+        short_name: "pascal",
+        unit_symbol: "Pa",
+    } alias Pa;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
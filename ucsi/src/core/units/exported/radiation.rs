@@ -0,0 +1,41 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::{
+        base::{Meter, Second},
+        kind,
+    },
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Becquerel based on unit!(Second ** { -1 }) {
+        full_name: "Becquerel",
+        short_name: "becquerel",
+        unit_symbol: "Bq",
+        kind: Some(kind::ACTIVITY),
+    } alias Bq;
+
+    unit Gray based on unit!((Meter ** { 2 }) / (Second ** { 2 })) {
+        full_name: "Gray",
+        short_name: "gray",
+        unit_symbol: "Gy",
+        kind: Some(kind::ABSORBED_DOSE),
+    } alias Gy;
+
+    unit Sievert based on unit!((Meter ** { 2 }) / (Second ** { 2 })) {
+        full_name: "Sievert",
+        short_name: "sievert",
+        unit_symbol: "Sv",
+        kind: Some(kind::DOSE_EQUIVALENT),
+    } alias Sv;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
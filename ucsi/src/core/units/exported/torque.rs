@@ -0,0 +1,27 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::{
+        base::{Kilogram, Meter, Second},
+        kind,
+    },
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit NewtonMeter based on unit!((Kilogram * (Meter ** { 2 })) / (Second ** { 2 })) {
+        full_name: "Newton meter",
+        short_name: "newton meter",
+        unit_symbol: "N m",
+        kind: Some(kind::TORQUE),
+    } alias Nm;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
@@ -0,0 +1,23 @@
+use cfg_if::cfg_if;
+
+use crate::{
+    core::units::base::{Ampere, Kilogram, Meter, Second},
+    macros::unit_def::si_exported_unit_def,
+    unit,
+};
+
+si_exported_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    unit Volt based on unit!((Kilogram * (Meter ** { 2 })) / ((Second ** { 3 }) * Ampere)) {
+        full_name: "Volt",
+        short_name: "volt",
+        unit_symbol: "V",
+    } alias V;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
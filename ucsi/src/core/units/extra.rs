@@ -0,0 +1,368 @@
+//! # The `extra` module
+//!
+//! Besides the seven SI base units, a [`BaseUnitMap`](super::base::BaseUnitMap)
+//! can carry a small number of extra, user-defined base dimensions,
+//! e.g. information (`bit`), events or pixels.
+//! They are checked like any SI base unit by [`CastFrom`](super::any::CastFrom),
+//! and work with [`unit!`](crate::unit), [`Value`](crate::Value) operators and the formatters.
+//!
+//! Use the [`si_base_unit_def!`](crate::macros::unit_def::si_base_unit_def) macro
+//! (requires the `typedef_macros` feature) to declare new base units.
+//! Every extra dimension is identified by the path of its base unit type,
+//! so two base units with the same symbol, e.g. a user-defined `bit` and
+//! [`information::Bit`](super::associated::information::Bit), are still different dimensions.
+//! Formatters only print the symbols, so such units look the same in formatted output.
+//!
+//! At most [`ExtraDimensions::CAPACITY`] different extra dimensions can be used in one unit,
+//! more dimensions are a compile-time error.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::macros::unit_def::si_base_unit_def;
+//! use ucsi::units::base::s;
+//! use ucsi::{unit, Value};
+//!
+//! si_base_unit_def! {
+//!     alias pub mod aliases;
+//!
+//!     /// Information.
+//!     unit Bit {
+//!         full_name: "Bit",
+//!         short_name: "bit",
+//!         unit_symbol: "bit",
+//!     } alias bit;
+//!
+//!     /// Count of events.
+//!     unit Event {
+//!         full_name: "Event",
+//!         short_name: "event",
+//!         unit_symbol: "event",
+//!     };
+//! }
+//!
+//! fn main() {
+//!     let size: Value<f64, Bit> = Value::new(8.0);
+//!     let time: Value<f64, s> = Value::new(2.0);
+//!     let rate: Value<f64, unit!(Bit / s)> = size / time;
+//!     let _: Value<f64, unit!(Bit * (s ** { -1 }))> = rate.cast();
+//! }
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::macros::unit_def::si_base_unit_def;
+//! # use ucsi::Value;
+//! # si_base_unit_def! {
+//! #     alias pub mod aliases;
+//! #     unit Bit { full_name: "Bit", short_name: "bit", unit_symbol: "bit" };
+//! #     unit Event { full_name: "Event", short_name: "event", unit_symbol: "event" };
+//! # }
+//! fn main() {
+//!     let size: Value<f64, Bit> = Value::new(8.0);
+//!     let count: Value<f64, Event> = size.cast();
+//! }
+//! ```
+//!
+//! ## Limitations
+//!
+//! Units with extra dimensions are not [`Canonicalize`](super::canonical::Canonicalize),
+//! since the type-level representation only covers the SI base units.
+
+use crate::{fraction::Fraction, units::any::SiOpsUnit};
+
+/// Const string comparison.
+pub(crate) const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Const lexicographical `lhs < rhs`.
+const fn str_lt(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    let mut i = 0;
+    while i < lhs.len() && i < rhs.len() {
+        if lhs[i] != rhs[i] {
+            return lhs[i] < rhs[i];
+        }
+        i += 1;
+    }
+    lhs.len() < rhs.len()
+}
+
+/// An extra base dimension and its exponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "const_dim", derive(core::marker::ConstParamTy))]
+pub struct ExtraDimension {
+    /// Unique identity, the path of the base unit type.
+    pub id: &'static str,
+    /// Symbol used by the formatters.
+    pub symbol: &'static str,
+    pub exponent: Fraction,
+}
+
+impl ExtraDimension {
+    /// Placeholder of an unused slot.
+    const VACANT: ExtraDimension = ExtraDimension {
+        id: "",
+        symbol: "",
+        exponent: Fraction::ZERO,
+    };
+
+    #[inline]
+    const fn is_vacant(&self) -> bool {
+        self.id.is_empty()
+    }
+}
+
+/// Extra base dimensions of a [`BaseUnitMap`](super::base::BaseUnitMap).
+///
+/// The entries are always kept sorted by identity, simplified and without zero exponents,
+/// so equal dimensions are also structurally equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "const_dim", derive(core::marker::ConstParamTy))]
+pub struct ExtraDimensions {
+    // `Option` is not a valid const parameter type, so unused slots are vacant entries.
+    entries: [ExtraDimension; ExtraDimensions::CAPACITY],
+}
+
+impl ExtraDimensions {
+    /// Maximum count of different extra dimensions.
+    pub const CAPACITY: usize = 4;
+
+    pub const EMPTY: ExtraDimensions = ExtraDimensions {
+        entries: [ExtraDimension::VACANT; Self::CAPACITY],
+    };
+
+    /// A single base dimension with exponent one.
+    ///
+    /// `id` must be unique among all base dimensions, `symbol` is only used for formatting.
+    #[inline]
+    pub const fn base(id: &'static str, symbol: &'static str) -> Self {
+        Self::EMPTY.insert(id, symbol, Fraction::ONE)
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        let mut i = 0;
+        while i < Self::CAPACITY && !self.entries[i].is_vacant() {
+            i += 1;
+        }
+        i
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries[0].is_vacant()
+    }
+
+    /// The exponent of the dimension `id`.
+    pub const fn get(&self, id: &str) -> Fraction {
+        let mut i = 0;
+        while i < self.len() {
+            if str_eq(self.entries[i].id, id) {
+                return self.entries[i].exponent;
+            }
+            i += 1;
+        }
+        Fraction::ZERO
+    }
+
+    /// The exponent of the base dimension of `U`, which must be declared by
+    /// [`si_base_unit_def!`](crate::macros::unit_def::si_base_unit_def).
+    pub const fn get_unit<U: SiOpsUnit>(&self) -> Fraction {
+        let base = U::UNIT_MAP.extra;
+        assert!(
+            base.len() == 1 && base.entries[0].exponent.eq(&Fraction::ONE),
+            "not a base unit of an extra dimension"
+        );
+        self.get(base.entries[0].id)
+    }
+
+    /// The slot of the dimension `id`, or the first vacant slot, `None` if all slots are used.
+    const fn slot(&self, id: &str) -> Option<usize> {
+        let mut i = 0;
+        while i < Self::CAPACITY {
+            let e = &self.entries[i];
+            if e.is_vacant() || str_eq(e.id, id) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Add `exponent` to the dimension `id`.
+    ///
+    /// Panics if there are more than [`CAPACITY`](Self::CAPACITY) different dimensions.
+    pub const fn insert(mut self, id: &'static str, symbol: &'static str, exponent: Fraction) -> Self {
+        assert!(!id.is_empty(), "the id of a dimension should never be empty");
+        let i = match self.slot(id) {
+            Some(i) => i,
+            None => panic!("too many extra dimensions, at most `ExtraDimensions::CAPACITY` are supported in one unit"),
+        };
+        // a vacant slot has a zero exponent
+        let exponent = self.entries[i].exponent.add(exponent);
+        self.entries[i] = ExtraDimension { id, symbol, exponent };
+        self.normalize()
+    }
+
+    /// Add `exponent` to the dimension `id`,
+    /// `None` if the exponent overflows or there are more than [`CAPACITY`](Self::CAPACITY) dimensions.
+    pub const fn checked_insert(
+        mut self,
+        id: &'static str,
+        symbol: &'static str,
+        exponent: Fraction,
+    ) -> Option<Self> {
+        assert!(!id.is_empty(), "the id of a dimension should never be empty");
+        let i = match self.slot(id) {
+            Some(i) => i,
+            None => return None,
+        };
+        let exponent = match self.entries[i].exponent.checked_add(exponent) {
+            Some(exponent) => exponent,
+            None => return None,
+        };
+        self.entries[i] = ExtraDimension { id, symbol, exponent };
+        Some(self.normalize())
+    }
+
+    #[inline]
+    pub const fn add(self, other: Self) -> Self {
+        let mut res = self;
+        let mut i = 0;
+        while i < other.len() {
+            let e = other.entries[i];
+            res = res.insert(e.id, e.symbol, e.exponent);
+            i += 1;
+        }
+        res
+    }
+
+    /// Add the exponents of `other`,
+    /// `None` if an exponent overflows or there are more than [`CAPACITY`](Self::CAPACITY) dimensions.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        let mut res = self;
+        let mut i = 0;
+        while i < other.len() {
+            let e = other.entries[i];
+            res = match res.checked_insert(e.id, e.symbol, e.exponent) {
+                Some(res) => res,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(res)
+    }
+
+    #[inline]
+    pub const fn simplify(self) -> Self {
+        self.normalize()
+    }
+
+    #[inline]
+    pub const fn neg(self) -> Self {
+        self.imul(-1)
+    }
+
+    #[inline]
+    pub const fn imul(mut self, rhs: i32) -> Self {
+        let mut i = 0;
+        while i < self.len() {
+            self.entries[i].exponent = self.entries[i].exponent.imul(rhs);
+            i += 1;
+        }
+        self.normalize()
+    }
+
+    /// Multiply the exponents by an integer, `None` if an exponent overflows.
+    pub const fn checked_imul(mut self, rhs: i32) -> Option<Self> {
+        let mut i = 0;
+        while i < self.len() {
+            self.entries[i].exponent = match self.entries[i].exponent.checked_imul(rhs) {
+                Some(exponent) => exponent,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(self.normalize())
+    }
+
+    #[inline]
+    pub const fn fmul(mut self, f: Fraction) -> Self {
+        let mut i = 0;
+        while i < self.len() {
+            self.entries[i].exponent = self.entries[i].exponent.fmul(f);
+            i += 1;
+        }
+        self.normalize()
+    }
+
+    /// Multiply the exponents by a fraction, `None` if an exponent overflows.
+    pub const fn checked_fmul(mut self, f: Fraction) -> Option<Self> {
+        let mut i = 0;
+        while i < self.len() {
+            self.entries[i].exponent = match self.entries[i].exponent.checked_fmul(f) {
+                Some(exponent) => exponent,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(self.normalize())
+    }
+
+    pub const fn eq(&self, rhs: &Self) -> bool {
+        let mut i = 0;
+        while i < Self::CAPACITY {
+            let (l, r) = (&self.entries[i], &rhs.entries[i]);
+            if !str_eq(l.id, r.id) || !l.exponent.eq(&r.exponent) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Iterate over the symbols and exponents of the dimensions, sorted by identity.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Fraction)> + '_ {
+        self.entries[..self.len()]
+            .iter()
+            .map(|e| (e.symbol, &e.exponent))
+    }
+
+    /// Drop zero exponents, simplify and sort the entries.
+    const fn normalize(self) -> Self {
+        let mut res = Self::EMPTY;
+        let mut len = 0;
+        let mut i = 0;
+        while i < Self::CAPACITY {
+            let e = self.entries[i];
+            if !e.is_vacant() && !e.exponent.is_zero() {
+                let e = ExtraDimension {
+                    exponent: e.exponent.simplify(),
+                    ..e
+                };
+                // insertion sort
+                let mut j = len;
+                while j > 0 && str_lt(e.id, res.entries[j - 1].id) {
+                    res.entries[j] = res.entries[j - 1];
+                    j -= 1;
+                }
+                res.entries[j] = e;
+                len += 1;
+            }
+            i += 1;
+        }
+        res
+    }
+}
@@ -0,0 +1,63 @@
+//! # The `kind` module
+//!
+//! Some quantities share the same dimension but are different kinds of quantity,
+//! e.g. torque (`N * m`) and energy (`J`), or frequency (`Hz`) and activity (`Bq`).
+//!
+//! A unit can carry an optional [`QuantityKind`] through [`SiOpsUnit::KIND`].
+//! [`CastFrom`](super::any::CastFrom) rejects casting between two different kinds,
+//! and [`Value::reinterpret_kind`](crate::Value::reinterpret_kind) must be used instead.
+//! The `cast_dim`, `add_dim` and `sub_dim` methods of the `dimension` module check kinds as well.
+//! Casting to a unit without a kind strips the kind,
+//! but attaching a kind, e.g. to the result of a multiplication, also requires `reinterpret_kind`.
+//! So a torque never becomes an energy through a unit without a kind.
+//!
+//! Type operations only carry a kind when it can be inferred unambiguously:
+//!
+//! - `L * R` keeps the kind of `L` if `R` is dimensionless, and vice versa.
+//! - `N / D` keeps the kind of `N` if `D` is dimensionless.
+//! - `B ** { 1 }` keeps the kind of `B`.
+//!
+//! Otherwise the result has no kind.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::exported::{J, Nm};
+//! use ucsi::Value;
+//!
+//! let torque: Value<f64, Nm> = Value::new(1.0);
+//! let energy: Value<f64, J> = torque.reinterpret_kind();
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::exported::{J, Nm};
+//! # use ucsi::Value;
+//! let torque: Value<f64, Nm> = Value::new(1.0);
+//! let energy: Value<f64, J> = torque.cast();
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::base::{kg, m, s};
+//! # use ucsi::units::exported::{J, Nm};
+//! # use ucsi::{unit, Value};
+//! let torque: Value<f64, Nm> = Value::new(1.0);
+//! let raw: Value<f64, unit!((kg * (m ** { 2 })) / (s ** { 2 }))> = torque.cast();
+//! let energy: Value<f64, J> = raw.cast();
+//! ```
+//!
+//! [`SiOpsUnit::KIND`]: super::any::SiOpsUnit::KIND
+
+pub use super::any::QuantityKind;
+
+/// Energy, work or heat. (`J`)
+pub const ENERGY: QuantityKind = QuantityKind::new("energy");
+/// Torque, or moment of force. (`N * m`)
+pub const TORQUE: QuantityKind = QuantityKind::new("torque");
+/// Frequency of a periodic phenomenon. (`Hz`)
+pub const FREQUENCY: QuantityKind = QuantityKind::new("frequency");
+/// Activity referred to a radionuclide. (`Bq`)
+pub const ACTIVITY: QuantityKind = QuantityKind::new("activity");
+/// Absorbed dose or kerma. (`Gy`)
+pub const ABSORBED_DOSE: QuantityKind = QuantityKind::new("absorbed dose");
+/// Dose equivalent. (`Sv`)
+pub const DOSE_EQUIVALENT: QuantityKind = QuantityKind::new("dose equivalent");
@@ -0,0 +1,143 @@
+//! # The `level` module
+//!
+//! Logarithmic quantities can not be mixed with linear ones:
+//! a [`Decibel`] gain is an associated unit, so it can be added to other gains,
+//! but not multiplied with a `Value<f64, W>`.
+//!
+//! - Gains ([`Decibel`], [`Bel`], [`Neper`]) are ratios without a reference.
+//!   Adding two gains multiplies the underlying ratios.
+//! - Levels ([`DecibelMilliwatt`], [`DecibelWatt`], [`DecibelVolt`], [`DecibelSoundPressure`])
+//!   are relative to a reference quantity of their base unit, see [`LevelUnit`].
+//!   A gain can be applied to a level with [`Value::with_gain`],
+//!   and the conversion to the linear quantity is explicit.
+//!
+//! New levels can be declared with the
+//! [`si_associated_unit_def!`](crate::macros::unit_def::si_associated_unit_def) macro
+//! and an implementation of [`LevelUnit`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::associated::logarithmic::{Decibel, DecibelMilliwatt};
+//! use ucsi::units::exported::power::W;
+//! use ucsi::Value;
+//!
+//! let input: Value<f64, DecibelMilliwatt> = Value::new(-30.0);
+//! let gain: Value<f64, Decibel> = Value::<f64, Decibel>::new(20.0) + Value::new(10.0);
+//! let output = input.with_gain(gain);
+//! assert_eq!(output.value, 0.0);
+//!
+//! let power: Value<f64, W> = output.to_linear();
+//! assert!((power.value - 1e-3).abs() < 1e-15);
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::associated::logarithmic::Decibel;
+//! # use ucsi::units::exported::power::W;
+//! # use ucsi::Value;
+//! let gain: Value<f64, Decibel> = Value::new(3.0);
+//! let power: Value<f64, W> = Value::new(1.0);
+//! let _ = power * gain;
+//! ```
+//!
+//! ## Related features
+//!
+//! - `use_std`: Enable conversions between logarithmic and linear values.
+//!
+//! [`Decibel`]: super::associated::logarithmic::Decibel
+//! [`Bel`]: super::associated::logarithmic::Bel
+//! [`Neper`]: super::associated::logarithmic::Neper
+//! [`DecibelMilliwatt`]: super::associated::logarithmic::DecibelMilliwatt
+//! [`DecibelWatt`]: super::associated::logarithmic::DecibelWatt
+//! [`DecibelVolt`]: super::associated::logarithmic::DecibelVolt
+//! [`DecibelSoundPressure`]: super::associated::logarithmic::DecibelSoundPressure
+
+use super::associated::{logarithmic::Decibel, SiAssociatedUnit};
+use crate::Value;
+
+/// Logarithmic levels in decibels, relative to a reference quantity of the base unit.
+pub trait LevelUnit: SiAssociatedUnit {
+    /// The reference quantity, in the base unit.
+    const REFERENCE: f64;
+    /// Whether the base unit is a root-power (field) quantity,
+    /// e.g. voltage or sound pressure, whose level is `20 log10(x / x0)`.
+    /// Otherwise the level of a power quantity is `10 log10(x / x0)`.
+    const ROOT_POWER: bool;
+}
+
+/// Decibels per decade of the linear quantity.
+const fn decade<U: LevelUnit>() -> f64 {
+    if U::ROOT_POWER {
+        20.0
+    } else {
+        10.0
+    }
+}
+
+impl<U: LevelUnit> Value<f64, U> {
+    /// Apply a gain (or an attenuation, if negative) to the level.
+    #[inline]
+    pub fn with_gain(self, gain: Value<f64, Decibel>) -> Self {
+        Value::new(self.value + gain.value)
+    }
+
+    /// The gain from `reference` to `self`.
+    #[inline]
+    pub fn gain_over(self, reference: Value<f64, U>) -> Value<f64, Decibel> {
+        Value::new(self.value - reference.value)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<U: LevelUnit> Value<f64, U> {
+    /// Convert the level to the linear quantity.
+    #[inline]
+    pub fn to_linear(self) -> Value<f64, U::BaseUnit> {
+        Value::new(U::REFERENCE * 10f64.powf(self.value / decade::<U>()))
+    }
+
+    /// Convert the linear quantity to a level.
+    #[inline]
+    pub fn from_linear(value: Value<f64, U::BaseUnit>) -> Self {
+        Value::new(decade::<U>() * (value.value / U::REFERENCE).log10())
+    }
+
+    /// Incoherent sum of two levels, e.g. the power of two uncorrelated sources.
+    #[inline]
+    pub fn power_sum(self, rhs: Value<f64, U>) -> Self {
+        let lhs = self.to_linear().value;
+        let rhs = rhs.to_linear().value;
+        if U::ROOT_POWER {
+            Self::from_linear(Value::new((lhs * lhs + rhs * rhs).sqrt()))
+        } else {
+            Self::from_linear(Value::new(lhs + rhs))
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl Value<f64, Decibel> {
+    /// Power ratio of the gain, `10^(dB / 10)`.
+    #[inline]
+    pub fn power_ratio(self) -> f64 {
+        10f64.powf(self.value / 10.0)
+    }
+
+    /// Amplitude (root-power) ratio of the gain, `10^(dB / 20)`.
+    #[inline]
+    pub fn amplitude_ratio(self) -> f64 {
+        10f64.powf(self.value / 20.0)
+    }
+
+    /// Gain of a power ratio.
+    #[inline]
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Value::new(10.0 * ratio.log10())
+    }
+
+    /// Gain of an amplitude (root-power) ratio.
+    #[inline]
+    pub fn from_amplitude_ratio(ratio: f64) -> Self {
+        Value::new(20.0 * ratio.log10())
+    }
+}
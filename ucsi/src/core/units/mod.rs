@@ -16,6 +16,23 @@
 //!   Enable `exported_types` and `associated_types`.
 //!   (Disabled by default. Included in the `full` feature.)
 //! 
+//! ### Type-level representation
+//! 
+//! - `canonical`:
+//!   Enable the [`canonical`] module, which normalizes units into a single rust type.
+//!   (Disabled by default. Included in the `full` feature.)
+//! - `type_dimension`:
+//!   Enable the [`dimension`] module, which checks dimensions with trait bounds,
+//!   and the [`quantity`] module, which offers marker traits for common quantities.
+//!   Implies the `canonical` feature.
+//!   (Disabled by default. Included in the `full` feature.)
+//! 
+//! ### Angle
+//! 
+//! - `angle`:
+//!   Make radian a base unit, see the [`angle`] module.
+//!   (Disabled by default. Not included in the `full` feature.)
+//! 
 //! ### Unit alias
 //! 
 //! - `alias_export`:
@@ -49,3 +66,40 @@ pub(crate) mod associated;
 
 /// Primitive configuration and traits of units.
 pub mod any;
+
+/// Kinds of quantities sharing the same dimension.
+pub mod kind;
+
+/// Angle units and trigonometry.
+pub mod angle;
+
+/// Exact rational scale factors of units.
+pub mod scale;
+
+/// Runtime metadata of the built-in units.
+pub mod registry;
+
+#[cfg(feature = "use_alloc")]
+/// Conversion between unit expressions parsed at runtime.
+pub mod convert;
+
+/// Logarithmic levels and gains.
+pub mod level;
+
+/// User-defined base dimensions.
+pub mod extra;
+
+/// Unit systems other than SI.
+pub mod system;
+
+#[cfg(feature = "canonical")]
+/// Canonical type-level representation of units.
+pub mod canonical;
+
+#[cfg(feature = "type_dimension")]
+/// Type-level dimension checks.
+pub mod dimension;
+
+#[cfg(feature = "type_dimension")]
+/// Marker traits for common quantities.
+pub mod quantity;
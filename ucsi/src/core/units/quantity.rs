@@ -0,0 +1,157 @@
+//! # The `quantity` module
+//!
+//! This module provides marker traits for common ISQ quantities,
+//! so generic physics functions can accept any unit of the given dimension,
+//! no matter how it is structurally spelled.
+//!
+//! Every trait is implemented for all [`ScaledUnit`]s whose [`Base`](ScaledUnit::Base)
+//! is a [`Dimension`] unit with the matching [`BaseUnitMap`](crate::units::base::BaseUnitMap),
+//! and the check is done by the type checker.
+//! This includes structural units and associated units with an exact factor,
+//! e.g. [`Gram`](crate::units::associated::weight::Gram),
+//! so convert the values with `rescale` before computing.
+//! Add a [`SiOpsUnit`](crate::units::any::SiOpsUnit) bound to use the values in arithmetic directly.
+//!
+//! [`Scaled`](crate::ops::Scaled) units are not [`Canonicalize`](crate::units::canonical::Canonicalize),
+//! since the canonical units drop the scale factor. Rescale them to a coherent unit first.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::associated::weight::Gram;
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::quantity::{Mass, Velocity};
+//! use ucsi::units::exported::force::Newton;
+//! use ucsi::unit;
+//! use ucsi::Value;
+//!
+//! type Joule = unit!(Newton * m);
+//!
+//! fn kinetic_energy<M: Mass, V: Velocity>(mass: Value<f64, M>, v: Value<f64, V>) -> Value<f64, Joule> {
+//!     let mass: Value<f64, kg> = mass.rescale();
+//!     let v: Value<f64, unit!(m / s)> = v.rescale();
+//!     (mass * v * v).pmul(0.5).cast_dim()
+//! }
+//!
+//! let e = kinetic_energy(Value::<f64, kg>::new(2.0), Value::<f64, unit!(m / s)>::new(3.0));
+//! assert_eq!(e.value, 9.0);
+//! let e = kinetic_energy(Value::<f64, Gram>::new(2000.0), Value::<f64, unit!(m / s)>::new(3.0));
+//! assert!((e.value - 9.0).abs() < 1e-12);
+//! ```
+//!
+//! Units of another dimension are rejected by the trait bound:
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::base::{kg, m};
+//! # use ucsi::units::quantity::Length;
+//! # use ucsi::Value;
+//! fn perimeter<L: Length>(side: Value<f64, L>) -> Value<f64, L> {
+//!     side.pmul(4.0)
+//! }
+//!
+//! perimeter(Value::<f64, kg>::new(1.0));
+//! ```
+//!
+//! So are `Scaled` units:
+//!
+//! ```rust,compile_fail
+//! # use ucsi::ops::Scaled;
+//! # use ucsi::units::base::m;
+//! # use ucsi::units::quantity::Length;
+//! # use ucsi::Value;
+//! fn perimeter<L: Length>(side: Value<f64, L>) -> Value<f64, L> {
+//!     side.pmul(4.0)
+//! }
+//!
+//! perimeter(Value::<f64, Scaled<m, 1000, 1>>::new(1.0));
+//! ```
+//!
+//! ## Related features
+//!
+//! - `type_dimension`: Enable this module.
+//!   (Disabled by default. Included in the `full` feature.)
+//! - `associated_types`: Enable the associated units, e.g. `Gram`.
+//!   (Disabled by default. Included in the `full` feature.)
+
+use crate::{
+    unit,
+    units::{
+        base::{Ampere, Candela, Kelvins, Kilogram, Meter, Mole, PureValue, Second},
+        canonical::Canonical,
+        dimension::Dimension,
+        scale::ScaledUnit,
+    },
+};
+
+macro_rules! __impl_quantity {
+    ($(
+        $(#[$attr:meta])*
+        quantity $name:ident: $unit:ty;
+    )+) => {
+        $(
+            $(#[$attr])*
+            pub trait $name: ScaledUnit<Base: Dimension<Canonical = Canonical<$unit>>> {}
+
+            impl<T: ScaledUnit<Base: Dimension<Canonical = Canonical<$unit>>>> $name for T {}
+        )+
+    };
+}
+
+__impl_quantity! {
+    /// Dimensionless quantity, e.g. ratio or count.
+    quantity Dimensionless: PureValue;
+
+    /// Length, e.g. `m`.
+    quantity Length: Meter;
+    /// Mass, e.g. `kg`.
+    quantity Mass: Kilogram;
+    /// Time, e.g. `s`.
+    quantity Time: Second;
+    /// Electric current, e.g. `A`.
+    quantity ElectricCurrent: Ampere;
+    /// Thermodynamic temperature, e.g. `K`.
+    quantity Temperature: Kelvins;
+    /// Amount of substance, e.g. `mol`.
+    quantity AmountOfSubstance: Mole;
+    /// Luminous intensity, e.g. `cd`.
+    quantity LuminousIntensity: Candela;
+
+    /// Area, e.g. `m ** {2}`.
+    quantity Area: unit!(Meter ** { 2 });
+    /// Volume, e.g. `m ** {3}`.
+    quantity Volume: unit!(Meter ** { 3 });
+    /// Frequency, e.g. `Hz`.
+    quantity Frequency: unit!(Second ** { -1 });
+    /// Velocity, e.g. `m / s`.
+    quantity Velocity: unit!(Meter / Second);
+    /// Acceleration, e.g. `m / (s ** {2})`.
+    quantity Acceleration: unit!(Meter / (Second ** { 2 }));
+    /// Density, e.g. `kg / (m ** {3})`.
+    quantity Density: unit!(Kilogram / (Meter ** { 3 }));
+    /// Momentum, e.g. `kg * (m / s)`.
+    quantity Momentum: unit!(Kilogram * (Meter / Second));
+    /// Force, e.g. `N`.
+    quantity Force: unit!((Kilogram * Meter) / (Second ** { 2 }));
+    /// Pressure, e.g. `Pa`.
+    quantity Pressure: unit!(Kilogram / (Meter * (Second ** { 2 })));
+    /// Energy, work or heat, e.g. `J`.
+    quantity Energy: unit!((Kilogram * (Meter ** { 2 })) / (Second ** { 2 }));
+    /// Power, e.g. `W`.
+    quantity Power: unit!((Kilogram * (Meter ** { 2 })) / (Second ** { 3 }));
+    /// Electric charge, e.g. `C`.
+    quantity ElectricCharge: unit!(Ampere * Second);
+    /// Voltage, e.g. `V`.
+    quantity Voltage: unit!((Kilogram * (Meter ** { 2 })) / ((Second ** { 3 }) * Ampere));
+    /// Electric resistance, e.g. `Ω`.
+    quantity Resistance: unit!((Kilogram * (Meter ** { 2 })) / ((Second ** { 3 }) * (Ampere ** { 2 })));
+    /// Capacitance, e.g. `F`.
+    quantity Capacitance: unit!(((Second ** { 4 }) * (Ampere ** { 2 })) / (Kilogram * (Meter ** { 2 })));
+    /// Magnetic flux, e.g. `Wb`.
+    quantity MagneticFlux: unit!((Kilogram * (Meter ** { 2 })) / ((Second ** { 2 }) * Ampere));
+    /// Magnetic flux density, e.g. `T`.
+    quantity MagneticFluxDensity: unit!(Kilogram / ((Second ** { 2 }) * Ampere));
+    /// Inductance, e.g. `H`.
+    quantity Inductance: unit!((Kilogram * (Meter ** { 2 })) / ((Second ** { 2 }) * (Ampere ** { 2 })));
+    /// Molar concentration, e.g. `mol / (m ** {3})`.
+    quantity Concentration: unit!(Mole / (Meter ** { 3 }));
+}
@@ -0,0 +1,392 @@
+//! # The `registry` module
+//!
+//! Unit types only exist at compile time,
+//! so parsers, formatters and other runtime code look units up in a registry instead.
+//!
+//! Every linear built-in unit is listed in [`BUILTIN_UNITS`] as a [`UnitEntry`],
+//! with its [`BaseUnitMap`] and its factor relative to the coherent unit of the dimension.
+//! Logarithmic units and the units of [other systems](super::system) are not listed,
+//! except the erg, which is listed with the dimension of the joule.
+//!
+//! With the `use_alloc` feature, a [`Registry`] starts from the built-in units
+//! and accepts units of downstream crates.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::core::units::registry::{Registry, UnitEntry};
+//! use ucsi::macros::unit_def::si_base_unit_def;
+//! use ucsi::units::base::Meter;
+//! use ucsi::units::any::SiOpsUnit;
+//!
+//! si_base_unit_def! {
+//!     alias pub mod aliases;
+//!
+//!     unit Pixel {
+//!         full_name: "Pixel",
+//!         short_name: "pixel",
+//!         unit_symbol: "px",
+//!     };
+//! }
+//!
+//! fn main() {
+//!     let mut registry = Registry::builtin();
+//!     registry.register(UnitEntry::defined::<Pixel>()).unwrap();
+//!
+//!     let mile = registry.by_symbol("mi").unwrap();
+//!     assert_eq!(mile.to_coherent(1.0), 1609.344);
+//!     assert_eq!(registry.by_name("pixel").unwrap().unit_symbol, "px");
+//!
+//!     let lengths = registry.compatible(&Meter::UNIT_MAP).count();
+//!     assert!(lengths > 5);
+//! }
+//! ```
+//!
+//! ## Related features
+//!
+//! - `use_alloc`: Enable [`Registry`].
+
+use core::fmt;
+
+use super::{
+    any::{SiDefinedUnit, SiOpsUnit},
+    associated::{
+        angle::{Arcminute, Arcsecond, Degree, Turn},
+        area::Hectare,
+        astronomy::{EarthMass, JulianYear, LightYear, Parsec, SiderealDay, SolarMass},
+        energy::{Calorie, ElectronVolt, Kilocalorie, KilowattHour},
+        imperial::{
+            BritishThermalUnit, Fahrenheit, Foot, ImperialFluidOunce, ImperialGallon, ImperialPint,
+            Inch, Mile, Ounce, Pound, PoundPerSquareInch, Stone, Yard, FAHRENHEIT_FACTOR,
+            FAHRENHEIT_OFFSET,
+        },
+        information::{
+            Bit, Byte, BytePerSecond, Gibibyte, Gigabit, GigabitPerSecond, Gigabyte,
+            GigabytePerSecond, Kibibyte, Kilobit, KilobitPerSecond, Kilobyte, KilobytePerSecond,
+            Mebibyte, Megabit, MegabitPerSecond, Megabyte, MegabytePerSecond, Pebibyte, Petabyte,
+            Tebibyte, Terabit, Terabyte,
+        },
+        length::AstronomicalUnit,
+        power::{MechanicalHorsepower, MetricHorsepower},
+        pressure::{Atmosphere, Bar, Millibar, MillimeterOfMercury, Torr},
+        time::{Day, Hour, Minute},
+        us_customary::{UsFluidOunce, UsGallon, UsPint, UsQuart},
+        volume::Litre,
+        weight::{Dalton, Gram, Tonne},
+        SiAssociatedUnit, CATALOGUE_UNITS,
+    },
+    base::{Ampere, BaseUnitMap, Candela, Kelvins, Kilogram, Meter, Mole, Radian, Second},
+    exported::{
+        energy::Joule,
+        force::Newton,
+        frequency::Hertz,
+        power::Watt,
+        pressure::Pascal,
+        radiation::{Becquerel, Gray, Sievert},
+        torque::NewtonMeter,
+        voltage::Volt,
+    },
+    scale::{LinearUnit, Scale, ScaledUnit},
+    system::cgs::Erg,
+};
+
+/// Runtime metadata of a unit.
+///
+/// A value `x` of the unit is `x * factor + offset` in the coherent unit of [`unit_map`](Self::unit_map).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitEntry {
+    pub full_name: &'static str,
+    pub short_name: &'static str,
+    pub unit_symbol: &'static str,
+    /// The dimension of the unit.
+    pub unit_map: BaseUnitMap,
+    /// The exact factor, `None` if the factor is not rational or the unit is affine.
+    pub scale: Option<Scale>,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+impl UnitEntry {
+    /// Entry of a base, exported or user-defined unit.
+    pub const fn defined<U: SiDefinedUnit + SiOpsUnit>() -> UnitEntry {
+        UnitEntry {
+            full_name: U::DEF.full_name,
+            short_name: U::DEF.short_name,
+            unit_symbol: U::DEF.unit_symbol,
+            unit_map: U::UNIT_MAP.simplify(),
+            scale: Some(U::SCALE),
+            factor: U::SCALE.to_f64(),
+            offset: 0.0,
+        }
+    }
+
+    /// Entry of an associated unit with an exact factor.
+    pub const fn exact<U: SiAssociatedUnit + ScaledUnit>() -> UnitEntry {
+        UnitEntry {
+            scale: Some(<U as ScaledUnit>::FACTOR),
+            factor: <U as ScaledUnit>::FACTOR.to_f64(),
+            ..UnitEntry::affine::<U>(1.0, 0.0)
+        }
+    }
+
+    /// Entry of an associated unit with a floating-point factor.
+    pub const fn linear<U: SiAssociatedUnit + LinearUnit>() -> UnitEntry {
+        UnitEntry::affine::<U>(<U as LinearUnit>::FACTOR, 0.0)
+    }
+
+    /// Entry of an associated unit, `x U = (x * factor + offset)` coherent units.
+    pub const fn affine<U: SiAssociatedUnit>(factor: f64, offset: f64) -> UnitEntry {
+        UnitEntry {
+            full_name: U::DEF.full_name,
+            short_name: U::DEF.short_name,
+            unit_symbol: U::DEF.unit_symbol,
+            unit_map: <U::BaseUnit as SiOpsUnit>::UNIT_MAP.simplify(),
+            scale: None,
+            factor,
+            offset,
+        }
+    }
+
+    /// Entry of a unit of another [system](super::system),
+    /// listed with the dimension of the SI unit `B`, `1 U = scale B`.
+    pub const fn system<U: SiDefinedUnit, B: SiOpsUnit>(scale: Scale) -> UnitEntry {
+        let scale = B::SCALE.mul(scale);
+        UnitEntry {
+            full_name: U::DEF.full_name,
+            short_name: U::DEF.short_name,
+            unit_symbol: U::DEF.unit_symbol,
+            unit_map: B::UNIT_MAP.simplify(),
+            scale: Some(scale),
+            factor: scale.to_f64(),
+            offset: 0.0,
+        }
+    }
+
+    /// Check if the unit has the dimension `unit_map`.
+    #[inline]
+    pub const fn is_compatible(&self, unit_map: &BaseUnitMap) -> bool {
+        self.unit_map.eq(&unit_map.simplify())
+    }
+
+    /// Check if the unit is linear, i.e. without an offset.
+    #[inline]
+    pub const fn is_linear(&self) -> bool {
+        self.offset == 0.0
+    }
+
+    /// Convert a value of the unit to the coherent unit.
+    #[inline]
+    pub const fn to_coherent(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    /// Convert a value of the coherent unit to the unit.
+    #[inline]
+    pub const fn from_coherent(&self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+}
+
+macro_rules! __builtin_units {
+    (
+        defined: [$($defined:ty),* $(,)?],
+        exact: [$($exact:ty),* $(,)?],
+        linear: [$($linear:ty),* $(,)?],
+        affine: [$($affine:ty: $factor:expr, $offset:expr);* $(;)?],
+        system: [$($system:ty: $si:ty, $scale:expr);* $(;)?] $(,)?
+    ) => {
+        &[
+            $(UnitEntry::defined::<$defined>(),)*
+            $(UnitEntry::exact::<$exact>(),)*
+            $(UnitEntry::linear::<$linear>(),)*
+            $(UnitEntry::affine::<$affine>($factor, $offset),)*
+            $(UnitEntry::system::<$system, $si>($scale),)*
+        ]
+    };
+}
+
+const LISTED_UNITS: &[UnitEntry] = __builtin_units! {
+    defined: [
+        Meter, Second, Kilogram, Ampere, Kelvins, Mole, Candela, Radian,
+        Newton, Joule, NewtonMeter, Hertz, Becquerel, Gray, Sievert, Pascal, Watt, Volt,
+        Bit,
+    ],
+    exact: [
+        Minute, Hour, Day, JulianYear,
+        Hectare, Litre,
+        AstronomicalUnit, LightYear,
+        Gram, Tonne,
+        Calorie, Kilocalorie, KilowattHour,
+        Bar, Millibar, Atmosphere, Torr, MillimeterOfMercury,
+        MetricHorsepower, MechanicalHorsepower,
+        Inch, Foot, Yard, Mile, Pound, Ounce, Stone,
+        ImperialGallon, ImperialPint, ImperialFluidOunce, PoundPerSquareInch, BritishThermalUnit,
+        UsGallon, UsQuart, UsPint, UsFluidOunce,
+        Byte, Kilobit, Megabit, Gigabit, Terabit,
+        Kilobyte, Megabyte, Gigabyte, Terabyte, Petabyte,
+        Kibibyte, Mebibyte, Gibibyte, Tebibyte, Pebibyte,
+        KilobitPerSecond, MegabitPerSecond, GigabitPerSecond,
+        BytePerSecond, KilobytePerSecond, MegabytePerSecond, GigabytePerSecond,
+    ],
+    linear: [
+        Degree, Arcminute, Arcsecond, Turn,
+        ElectronVolt, Dalton,
+        Parsec, SolarMass, EarthMass, SiderealDay,
+    ],
+    affine: [
+        Fahrenheit: FAHRENHEIT_FACTOR, FAHRENHEIT_OFFSET;
+    ],
+    system: [
+        Erg: Joule, Scale::new(1, 10_000_000);
+    ],
+};
+
+const BUILTIN_COUNT: usize = {
+    let mut count = LISTED_UNITS.len();
+    let mut i = 0;
+    while i < CATALOGUE_UNITS.len() {
+        count += CATALOGUE_UNITS[i].len();
+        i += 1;
+    }
+    count
+};
+
+const fn concat_entries<const N: usize>(first: &[UnitEntry], rest: &[&[UnitEntry]]) -> [UnitEntry; N] {
+    let mut entries = [first[0]; N];
+    let mut n = 0;
+    while n < first.len() {
+        entries[n] = first[n];
+        n += 1;
+    }
+    let mut i = 0;
+    while i < rest.len() {
+        let mut j = 0;
+        while j < rest[i].len() {
+            entries[n] = rest[i][j];
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    entries
+}
+
+/// All linear built-in units, the affine temperature units,
+/// and the units generated from the catalogue files.
+pub const BUILTIN_UNITS: &[UnitEntry] = &concat_entries::<BUILTIN_COUNT>(LISTED_UNITS, CATALOGUE_UNITS);
+
+/// Find a unit by its exact symbol, e.g. `"m"` or `"mi"`.
+///
+/// Prefixed symbols like `"km"` are not registered,
+/// use [`parse_unit`](super::convert::parse_unit) to resolve them.
+pub fn find_by_symbol<'a>(entries: &'a [UnitEntry], symbol: &str) -> Option<&'a UnitEntry> {
+    entries.iter().find(|e| e.unit_symbol == symbol)
+}
+
+/// Find a unit by its full or short name, ignoring ASCII case.
+pub fn find_by_name<'a>(entries: &'a [UnitEntry], name: &str) -> Option<&'a UnitEntry> {
+    entries
+        .iter()
+        .find(|e| e.full_name.eq_ignore_ascii_case(name) || e.short_name.eq_ignore_ascii_case(name))
+}
+
+/// Errors of unit registration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistryError {
+    /// A unit with the same symbol is already registered.
+    DuplicateSymbol(&'static str),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::DuplicateSymbol(symbol) => {
+                write!(f, "unit symbol `{}` is already registered", symbol)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for RegistryError {}
+
+#[cfg(feature = "use_alloc")]
+mod registry_impl {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use super::{find_by_name, find_by_symbol, RegistryError, UnitEntry, BUILTIN_UNITS};
+    use crate::units::base::BaseUnitMap;
+
+    /// A set of units with unique symbols.
+    #[derive(Debug, Clone, Default)]
+    pub struct Registry {
+        entries: Vec<UnitEntry>,
+    }
+
+    impl Registry {
+        /// An empty registry.
+        pub const fn new() -> Registry {
+            Registry {
+                entries: Vec::new(),
+            }
+        }
+
+        /// A registry with all [built-in units](BUILTIN_UNITS).
+        pub fn builtin() -> Registry {
+            Registry {
+                entries: BUILTIN_UNITS.to_vec(),
+            }
+        }
+
+        /// Add a unit, failing if its symbol is already registered.
+        pub fn register(&mut self, entry: UnitEntry) -> Result<(), RegistryError> {
+            if self.by_symbol(entry.unit_symbol).is_some() {
+                return Err(RegistryError::DuplicateSymbol(entry.unit_symbol));
+            }
+            self.entries.push(entry);
+            Ok(())
+        }
+
+        /// Add several units, stopping at the first duplicated symbol.
+        pub fn register_all(
+            &mut self,
+            entries: impl IntoIterator<Item = UnitEntry>,
+        ) -> Result<(), RegistryError> {
+            entries.into_iter().try_for_each(|entry| self.register(entry))
+        }
+
+        #[inline]
+        pub fn entries(&self) -> &[UnitEntry] {
+            &self.entries
+        }
+
+        /// See [`find_by_symbol`].
+        #[inline]
+        pub fn by_symbol(&self, symbol: &str) -> Option<&UnitEntry> {
+            find_by_symbol(&self.entries, symbol)
+        }
+
+        /// See [`find_by_name`].
+        #[inline]
+        pub fn by_name(&self, name: &str) -> Option<&UnitEntry> {
+            find_by_name(&self.entries, name)
+        }
+
+        /// Find a unit by its symbol, or by its name if no symbol matches.
+        pub fn lookup(&self, key: &str) -> Option<&UnitEntry> {
+            self.by_symbol(key).or_else(|| self.by_name(key))
+        }
+
+        /// All units with the dimension `unit_map`.
+        pub fn compatible<'a>(
+            &'a self,
+            unit_map: &'a BaseUnitMap,
+        ) -> impl Iterator<Item = &'a UnitEntry> + 'a {
+            self.entries.iter().filter(move |e| e.is_compatible(unit_map))
+        }
+    }
+}
+
+#[cfg(feature = "use_alloc")]
+pub use registry_impl::Registry;
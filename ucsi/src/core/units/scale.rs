@@ -0,0 +1,437 @@
+//! # The `scale` module
+//!
+//! Every [`SiOpsUnit`] carries an exact rational [`Scale`] factor relative to
+//! the coherent unit of its dimension, see [`SiOpsUnit::SCALE`].
+//! Base, exported and derived units have the factor `1`,
+//! and [`Scaled<U, N, D>`](crate::ops::Scaled) multiplies the factor of `U` by `N / D`.
+//! The factor composes through `*`, `/` and `**` in [`unit!`](crate::unit).
+//!
+//! [`cast`](crate::Value::cast) only changes the type, so it rejects units with different factors.
+//! Use `rescale` instead, which multiplies the value by the combined factor computed at compile time.
+//! Integer rescaling multiplies first and then truncates towards zero,
+//! it panics if the result does not fit into the integer type.
+//!
+//! Associated units with an exact conversion, e.g. gram or hour, implement [`ScaledUnit`]
+//! and can be rescaled as well.
+//! The `rescale_add`, `rescale_sub`, `rescale_eq` and `rescale_partial_cmp` methods
+//! work on operands with different factors, and convert the right-hand side to the left-hand unit first.
+//! Operands of different dimensions are rejected at compile time.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::ops::Scaled;
+//! use ucsi::units::base::{m, s};
+//! use ucsi::{unit, Value};
+//!
+//! type Km = Scaled<m, 1000, 1>;
+//! type Hour = Scaled<s, 3600, 1>;
+//! type KmPerHour = unit!(Km / Hour);
+//!
+//! let speed: Value<f64, KmPerHour> = Value::new(72.0);
+//! let speed: Value<f64, unit!(m / s)> = speed.rescale();
+//! assert!((speed.value - 20.0).abs() < 1e-12);
+//!
+//! const AREA: Value<u64, unit!(m ** { 2 })> = Value::<u64, unit!(Km ** { 2 })>::new(3).rescale();
+//! assert_eq!(AREA.value, 3_000_000);
+//! ```
+//!
+//! Mixed-unit arithmetic:
+//!
+//! ```rust
+//! use ucsi::units::associated::{time::Hour, weight::Gram};
+//! use ucsi::units::base::{kg, s};
+//! use ucsi::Value;
+//!
+//! let mass = Value::<f64, kg>::new(1.0).rescale_add(Value::<f64, Gram>::new(250.0));
+//! assert!((mass.value - 1.25).abs() < 1e-12);
+//!
+//! const TIME: Value<u32, s> = Value::<u32, s>::new(30).rescale_add(Value::<u32, Hour>::new(1));
+//! assert_eq!(TIME.value, 3630);
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::associated::time::Hour;
+//! # use ucsi::units::base::kg;
+//! # use ucsi::Value;
+//! let _ = Value::<f64, kg>::new(1.0).rescale_add(Value::<f64, Hour>::new(1.0));
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::ops::Scaled;
+//! # use ucsi::units::base::m;
+//! # use ucsi::Value;
+//! type Km = Scaled<m, 1000, 1>;
+//! let distance: Value<f64, Km> = Value::new(1.0);
+//! let distance: Value<f64, m> = distance.cast();
+//! ```
+//!
+//! [`SiOpsUnit`]: super::any::SiOpsUnit
+//! [`SiOpsUnit::SCALE`]: super::any::SiOpsUnit::SCALE
+
+use core::{cmp::Ordering, fmt};
+
+use super::any::{can_cast_kind_or_panic, is_same_type_or_panic, SiAnyUnit, SiOpsUnit};
+use crate::Value;
+
+/// An exact, non-zero rational factor.
+///
+/// The factor is always reduced, and the denominator is positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scale {
+    num: i128,
+    den: i128,
+}
+
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    a = a.abs();
+    b = b.abs();
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+const fn checked(value: Option<Scale>) -> Scale {
+    match value {
+        Some(value) => value,
+        None => panic!("scale factor overflow"),
+    }
+}
+
+impl Scale {
+    pub const ONE: Scale = Scale { num: 1, den: 1 };
+
+    #[inline]
+    pub const fn new(num: i64, den: u64) -> Scale {
+        Scale::reduce(num as i128, den as i128)
+    }
+
+    const fn reduce(num: i128, den: i128) -> Scale {
+        if num == 0 || den == 0 {
+            panic!("scale factor must be non-zero and finite")
+        }
+        let g = gcd(num, den);
+        let sign = if den < 0 { -1 } else { 1 };
+        Scale {
+            num: sign * num / g,
+            den: sign * den / g,
+        }
+    }
+
+    #[inline]
+    pub const fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    #[inline]
+    pub const fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    #[inline]
+    pub const fn eq(&self, rhs: &Self) -> bool {
+        self.num == rhs.num && self.den == rhs.den
+    }
+
+    #[inline]
+    pub const fn is_one(&self) -> bool {
+        self.eq(&Scale::ONE)
+    }
+
+    #[inline]
+    pub const fn mul(self, rhs: Self) -> Self {
+        checked(self.checked_mul(rhs))
+    }
+
+    /// Multiply, `None` if the numerator or denominator overflows.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross-reduce first to keep the intermediate values small
+        let g1 = gcd(self.num, rhs.den);
+        let g2 = gcd(rhs.num, self.den);
+        match (
+            (self.num / g1).checked_mul(rhs.num / g2),
+            (self.den / g2).checked_mul(rhs.den / g1),
+        ) {
+            (Some(num), Some(den)) => Some(Scale::reduce(num, den)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub const fn recip(self) -> Self {
+        Scale::reduce(self.den, self.num)
+    }
+
+    #[inline]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.recip())
+    }
+
+    /// Divide, `None` if the numerator or denominator overflows.
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip())
+    }
+
+    #[inline]
+    pub const fn powi(self, exp: i32) -> Self {
+        checked(self.checked_powi(exp))
+    }
+
+    /// Raise to an integer power, `None` if the numerator or denominator overflows.
+    pub const fn checked_powi(self, exp: i32) -> Option<Self> {
+        if self.is_one() {
+            return Some(Scale::ONE);
+        }
+        let mut base = if exp < 0 { self.recip() } else { self };
+        let mut exp = exp.unsigned_abs();
+        let mut result = Scale::ONE;
+        // exponentiation by squaring
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = match result.checked_mul(base) {
+                    Some(result) => result,
+                    None => return None,
+                };
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = match base.checked_mul(base) {
+                    Some(base) => base,
+                    None => return None,
+                };
+            }
+        }
+        Some(result)
+    }
+
+    /// Raise to a fractional power, only exact for integer exponents and the factor `1`.
+    pub const fn powf(self, num: i32, den: u32) -> Self {
+        if den == 0 {
+            panic!("zero denominator in the exponent")
+        }
+        if num % den as i32 == 0 {
+            self.powi(num / den as i32)
+        } else if self.is_one() {
+            Scale::ONE
+        } else {
+            panic!("cannot raise a scaled unit to a fractional power")
+        }
+    }
+
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    /// Multiply an integer by the factor, truncating towards zero.
+    ///
+    /// Panics if the product overflows `i128`.
+    #[inline]
+    pub const fn apply_i128(self, value: i128) -> i128 {
+        match value.checked_mul(self.num) {
+            Some(value) => value / self.den,
+            None => panic!("integer overflow in unit conversion"),
+        }
+    }
+
+    pub fn format_latex(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        if self.den == 1 {
+            write!(w, "{}", self.num)
+        } else {
+            write!(w, "\\frac{{{}}}{{{}}}", self.num, self.den)
+        }
+    }
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Units with an exact factor relative to the coherent unit of their dimension.
+///
+/// Every [`SiOpsUnit`] is a scaled unit of itself with the factor [`SiOpsUnit::SCALE`].
+/// Associated units with an exact rational conversion, e.g. gram or hour, are also scaled units.
+pub trait ScaledUnit: SiAnyUnit {
+    /// The unit providing the dimension and the kind.
+    type Base: SiOpsUnit;
+    /// `1 Self = FACTOR` coherent units.
+    const FACTOR: Scale;
+}
+
+impl<T: SiOpsUnit> ScaledUnit for T {
+    type Base = T;
+    const FACTOR: Scale = T::SCALE;
+}
+
+/// Units with a floating-point factor relative to the coherent unit of their dimension,
+/// e.g. degree or electronvolt.
+///
+/// Every associated unit with a linear conversion implements this trait,
+/// including the ones with an exact [`ScaledUnit::FACTOR`].
+pub trait LinearUnit: SiAnyUnit {
+    /// The unit providing the dimension and the kind.
+    type Base: SiOpsUnit;
+    /// `1 Self = FACTOR` coherent units.
+    const FACTOR: f64;
+}
+
+/// Conversion factor between two units of the same dimension and kind.
+pub trait RescaleFrom<T: ScaledUnit> {
+    /// `1 T = FACTOR Self`.
+    const FACTOR: Scale;
+}
+
+impl<T: ScaledUnit, B: ScaledUnit> RescaleFrom<T> for B {
+    const FACTOR: Scale = {
+        is_same_type_or_panic::<T::Base, B::Base>();
+        can_cast_kind_or_panic::<T::Base, B::Base>();
+        <T as ScaledUnit>::FACTOR.div(<B as ScaledUnit>::FACTOR)
+    };
+}
+
+/// Multiply an integer of type `$ty` by a [`Scale`] in `i128`, truncating towards zero.
+///
+/// Panics if the value does not fit into `i128`, or the result does not fit into `$ty`.
+macro_rules! __impl_checked_int_rescale {
+    ($value:expr, $ty:ty, $factor:expr) => {{
+        let value: $ty = $value;
+        let wide = value as i128;
+        // only `u128` values above `i128::MAX` can fail here
+        if wide as $ty != value || wide < <$ty>::MIN as i128 {
+            panic!("integer overflow in unit conversion")
+        }
+        let wide = $factor.apply_i128(wide);
+        if wide < <$ty>::MIN as i128 || wide as $ty as i128 != wide {
+            panic!(concat!("integer overflow in unit conversion, the result does not fit into `", stringify!($ty), "`"))
+        }
+        wide as $ty
+    }};
+}
+
+pub(crate) use __impl_checked_int_rescale as checked_int_rescale;
+
+/// Mixed-unit operations, the right-hand side is rescaled to the left-hand unit first.
+macro_rules! __impl_rescale_ops {
+    ($ty:ty, |$l1:ident, $r1:ident| $add:expr, |$l2:ident, $r2:ident| $sub:expr) => {
+        /// Add a value of the same dimension with another scale factor.
+        ///
+        /// The right-hand side is converted to the left-hand unit first.
+        #[inline]
+        pub const fn rescale_add<B: ScaledUnit>(self, rhs: Value<$ty, B>) -> Value<$ty, T>
+        where
+            T: RescaleFrom<B>,
+        {
+            let $l1 = self.value;
+            let $r1 = rhs.rescale::<T>().value;
+            Value::new($add)
+        }
+
+        /// Subtract a value of the same dimension with another scale factor.
+        ///
+        /// The right-hand side is converted to the left-hand unit first.
+        #[inline]
+        pub const fn rescale_sub<B: ScaledUnit>(self, rhs: Value<$ty, B>) -> Value<$ty, T>
+        where
+            T: RescaleFrom<B>,
+        {
+            let $l2 = self.value;
+            let $r2 = rhs.rescale::<T>().value;
+            Value::new($sub)
+        }
+
+        /// Compare with a value of the same dimension with another scale factor.
+        ///
+        /// The right-hand side is converted to the left-hand unit first.
+        #[inline]
+        pub fn rescale_partial_cmp<B: ScaledUnit>(&self, rhs: Value<$ty, B>) -> Option<Ordering>
+        where
+            T: RescaleFrom<B>,
+        {
+            self.value.partial_cmp(&rhs.rescale::<T>().value)
+        }
+
+        /// Check equality with a value of the same dimension with another scale factor.
+        ///
+        /// The right-hand side is converted to the left-hand unit first.
+        #[inline]
+        pub fn rescale_eq<B: ScaledUnit>(&self, rhs: Value<$ty, B>) -> bool
+        where
+            T: RescaleFrom<B>,
+        {
+            self.value == rhs.rescale::<T>().value
+        }
+    };
+}
+
+macro_rules! __impl_int_rescale {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<T: ScaledUnit> Value<$ty, T> {
+                /// Convert to a unit of the same dimension with another scale factor.
+                #[inline]
+                pub const fn rescale<B: ScaledUnit + RescaleFrom<T>>(self) -> Value<$ty, B> {
+                    let factor = <B as RescaleFrom<T>>::FACTOR;
+                    Value::new(checked_int_rescale!(self.value, $ty, factor))
+                }
+
+                __impl_rescale_ops!($ty, |lhs, rhs| lhs + rhs, |lhs, rhs| lhs - rhs);
+            }
+        )+
+    };
+}
+
+macro_rules! __impl_float_rescale {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<T: ScaledUnit> Value<$ty, T> {
+                /// Convert to a unit of the same dimension with another scale factor.
+                #[inline]
+                pub const fn rescale<B: ScaledUnit + RescaleFrom<T>>(self) -> Value<$ty, B> {
+                    let factor = <B as RescaleFrom<T>>::FACTOR;
+                    Value::new(
+                        self.value * factor.numerator() as $ty / factor.denominator() as $ty,
+                    )
+                }
+
+                __impl_rescale_ops!($ty, |lhs, rhs| lhs + rhs, |lhs, rhs| lhs - rhs);
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "const_soft_float")]
+macro_rules! __impl_const_float_rescale {
+    ($($ty:ident: $prim:ty),+ $(,)?) => {
+        $(
+            impl<T: ScaledUnit> Value<crate::$ty, T> {
+                /// Convert to a unit of the same dimension with another scale factor.
+                #[inline]
+                pub const fn rescale<B: ScaledUnit + RescaleFrom<T>>(self) -> Value<crate::$ty, B> {
+                    let factor = <B as RescaleFrom<T>>::FACTOR;
+                    Value::new(
+                        self.value
+                            .mul(crate::$ty(factor.numerator() as $prim))
+                            .div(crate::$ty(factor.denominator() as $prim)),
+                    )
+                }
+
+                __impl_rescale_ops!(crate::$ty, |lhs, rhs| lhs.add(rhs), |lhs, rhs| lhs.sub(rhs));
+            }
+        )+
+    };
+}
+
+__impl_int_rescale!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize);
+__impl_float_rescale!(f32, f64);
+#[cfg(feature = "const_soft_float")]
+__impl_const_float_rescale!(SoftF32: f32, SoftF64: f64);
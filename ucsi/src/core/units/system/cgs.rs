@@ -0,0 +1,161 @@
+//! # The CGS system
+//!
+//! The centimetre–gram–second system for mechanics.
+//! [`Centimeter`], [`Gram`] and [`Second`] are the base units,
+//! each of them is a new base dimension.
+//! Their symbols are `cm_cgs`, `g_cgs` and `s_cgs`,
+//! so formatted CGS units are never mistaken for SI units.
+//!
+//! Conversion to SI is well-defined for every unit built from the three base units:
+//! `1 cm = 1e-2 m`, `1 g = 1e-3 kg` and `1 s = 1 s`.
+
+use cfg_if::cfg_if;
+
+use crate::{
+    fraction::Fraction,
+    macros::unit_def::si_base_unit_def,
+    unit,
+    units::{
+        any::SiOpsUnit,
+        base::BaseUnitMap,
+    },
+};
+
+use super::{powi, scale_ratio, system_unit_def, SystemCastFrom, UnitSystem};
+
+/// The CGS system.
+pub struct Cgs;
+
+impl UnitSystem for Cgs {
+    const NAME: &'static str = "CGS";
+}
+
+si_base_unit_def! {
+    alias pub(crate) mod impl_base_aliases;
+
+    /// CGS base unit of length.
+    unit Centimeter {
+        full_name: "Centimeter",
+        short_name: "centimeter",
+        unit_symbol: "cm_cgs",
+    } alias cm;
+
+    /// CGS base unit of mass.
+    unit Gram {
+        full_name: "Gram",
+        short_name: "gram",
+        unit_symbol: "g_cgs",
+    } alias g;
+
+    /// CGS base unit of time.
+    ///
+    /// This is a different dimension from [`base::Second`](crate::units::base::Second).
+    unit Second {
+        full_name: "Second",
+        short_name: "second",
+        unit_symbol: "s_cgs",
+    };
+}
+
+system_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// CGS unit of force.
+    unit Dyne based on unit!((Gram * Centimeter) / (Second ** { 2 })) {
+        full_name: "Dyne",
+        short_name: "dyne",
+        unit_symbol: "dyn",
+    };
+
+    /// CGS unit of energy.
+    unit Erg based on unit!((Gram * (Centimeter ** { 2 })) / (Second ** { 2 })) {
+        full_name: "Erg",
+        short_name: "erg",
+        unit_symbol: "erg",
+    } alias erg;
+
+    /// CGS unit of pressure.
+    unit Barye based on unit!(Gram / (Centimeter * (Second ** { 2 }))) {
+        full_name: "Barye",
+        short_name: "barye",
+        unit_symbol: "Ba",
+    } alias Ba;
+
+    /// CGS unit of acceleration.
+    unit Gal based on unit!(Centimeter / (Second ** { 2 })) {
+        full_name: "Gal",
+        short_name: "gal",
+        unit_symbol: "Gal",
+    };
+
+    /// CGS unit of dynamic viscosity.
+    unit Poise based on unit!(Gram / (Centimeter * Second)) {
+        full_name: "Poise",
+        short_name: "poise",
+        unit_symbol: "P",
+    } alias P;
+
+    /// CGS unit of kinematic viscosity.
+    unit Stokes based on unit!((Centimeter ** { 2 }) / Second) {
+        full_name: "Stokes",
+        short_name: "stokes",
+        unit_symbol: "St",
+    } alias St;
+}
+
+/// Map the CGS base dimensions of `map` to SI.
+///
+/// Returns the SI dimension and the decimal exponent of the factor.
+pub(crate) const fn cgs_to_si(map: BaseUnitMap) -> (BaseUnitMap, Fraction) {
+    let cm = map.extra.get_unit::<Centimeter>();
+    let g = map.extra.get_unit::<Gram>();
+    let s = map.extra.get_unit::<Second>();
+
+    let mut si = map;
+    si.meter = si.meter.add(cm);
+    si.kilogram = si.kilogram.add(g);
+    si.second = si.second.add(s);
+    si.extra = si
+        .extra
+        .add(<Centimeter as SiOpsUnit>::UNIT_MAP.extra.fmul(cm.neg()))
+        .add(<Gram as SiOpsUnit>::UNIT_MAP.extra.fmul(g.neg()))
+        .add(<Second as SiOpsUnit>::UNIT_MAP.extra.fmul(s.neg()));
+
+    (si.simplify(), cm.imul(-2).add(g.imul(-3)))
+}
+
+/// `1 T = factor B`, converting through SI with `to_si`.
+pub(crate) const fn decimal_factor(
+    from: (BaseUnitMap, Fraction),
+    to: (BaseUnitMap, Fraction),
+) -> f64 {
+    if !from.0.eq(&to.0) {
+        panic!("cannot convert between unit systems")
+    }
+    let exp = from.1.add(to.1.neg());
+    if exp.denominator().get() != 1 {
+        panic!("conversion factor is not an integer power of ten")
+    }
+    powi(10.0, exp.numerator())
+}
+
+impl<T: SiOpsUnit, B: SiOpsUnit> SystemCastFrom<Cgs, T> for B {
+    const FACTOR: f64 =
+        decimal_factor(cgs_to_si(T::UNIT_MAP), cgs_to_si(B::UNIT_MAP)) * scale_ratio::<T, B>();
+}
+
+mod __collect_impl {
+    pub use super::impl_aliases::*;
+    pub use super::impl_base_aliases::*;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_hidden")] {
+    } else if #[cfg(feature = "alias_mod")] {
+        pub mod aliases {
+            pub use super::__collect_impl::*;
+        }
+    } else if #[cfg(feature = "alias_export")] {
+        pub use __collect_impl::*;
+    }
+}
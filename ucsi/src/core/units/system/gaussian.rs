@@ -0,0 +1,84 @@
+//! # The CGS-Gaussian system
+//!
+//! The Gaussian system extends [CGS](super::cgs) to electromagnetism
+//! without any new base dimension, e.g. `1 Fr = 1 g^(1/2) cm^(3/2) s^(-1)`.
+//!
+//! Electromagnetic units have different dimensions from their SI counterparts,
+//! so only the mechanical part can be converted to SI.
+
+use cfg_if::cfg_if;
+
+use crate::{unit, units::any::SiOpsUnit};
+
+use super::{
+    cgs::{cgs_to_si, decimal_factor},
+    scale_ratio, system_unit_def, SystemCastFrom, UnitSystem,
+};
+
+pub use super::cgs::{Barye, Centimeter, Dyne, Erg, Gal, Gram, Poise, Second, Stokes};
+
+/// The CGS-Gaussian system.
+pub struct Gaussian;
+
+impl UnitSystem for Gaussian {
+    const NAME: &'static str = "Gaussian";
+}
+
+system_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// Gaussian unit of electric charge, also known as franklin.
+    unit Statcoulomb based on unit!(((Gram ** { 1 / 2 }) * (Centimeter ** { 3 / 2 })) / Second) {
+        full_name: "Statcoulomb",
+        short_name: "statcoulomb",
+        unit_symbol: "statC",
+    } alias statC, Fr;
+
+    /// Gaussian unit of electric current.
+    unit Statampere based on unit!(((Gram ** { 1 / 2 }) * (Centimeter ** { 3 / 2 })) / (Second ** { 2 })) {
+        full_name: "Statampere",
+        short_name: "statampere",
+        unit_symbol: "statA",
+    } alias statA;
+
+    /// Gaussian unit of electric potential.
+    unit Statvolt based on unit!(((Gram ** { 1 / 2 }) * (Centimeter ** { 1 / 2 })) / Second) {
+        full_name: "Statvolt",
+        short_name: "statvolt",
+        unit_symbol: "statV",
+    } alias statV;
+
+    /// Gaussian unit of magnetic flux density.
+    unit Gauss based on unit!((Gram ** { 1 / 2 }) / ((Centimeter ** { 1 / 2 }) * Second)) {
+        full_name: "Gauss",
+        short_name: "gauss",
+        unit_symbol: "G",
+    } alias G;
+
+    /// Gaussian unit of magnetic flux.
+    unit Maxwell based on unit!(((Gram ** { 1 / 2 }) * (Centimeter ** { 3 / 2 })) / Second) {
+        full_name: "Maxwell",
+        short_name: "maxwell",
+        unit_symbol: "Mx",
+    } alias Mx;
+}
+
+impl<T: SiOpsUnit, B: SiOpsUnit> SystemCastFrom<Gaussian, T> for B {
+    const FACTOR: f64 =
+        decimal_factor(cgs_to_si(T::UNIT_MAP), cgs_to_si(B::UNIT_MAP)) * scale_ratio::<T, B>();
+}
+
+mod __collect_impl {
+    pub use super::impl_aliases::*;
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_hidden")] {
+    } else if #[cfg(feature = "alias_mod")] {
+        pub mod aliases {
+            pub use super::__collect_impl::*;
+        }
+    } else if #[cfg(feature = "alias_export")] {
+        pub use __collect_impl::*;
+    }
+}
@@ -0,0 +1,158 @@
+//! # The `system` module
+//!
+//! Besides SI, this module offers some other unit systems:
+//!
+//! - [`cgs`]: The centimetre–gram–second system.
+//! - [`gaussian`]: The CGS-Gaussian system for electromagnetism.
+//! - [`natural`]: Natural units with `ħ = c = 1`.
+//!
+//! Every system declares its own base dimensions with the [extra dimensions](super::extra),
+//! so values from different systems never mix by accident:
+//! a `Value<f64, cgs::Centimeter>` is neither a `Value<f64, Meter>`,
+//! nor can it be [`cast`](crate::Value::cast) to one.
+//!
+//! Use [`Value::convert_system`] to convert between a system and SI,
+//! or inside a system, where the conversion is well-defined.
+//! The check is done at compile time, and the conversion factor is computed at compile time, too.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::exported::energy::Joule;
+//! use ucsi::units::system::cgs::{Cgs, Erg};
+//! use ucsi::units::system::natural::{ElectronVolt, Natural};
+//! use ucsi::{unit, Value};
+//!
+//! let energy: Value<f64, Erg> = Value::new(1.0e7);
+//! let energy: Value<f64, Joule> = energy.convert_system::<Cgs, _>();
+//! assert!((energy.value - 1.0).abs() < 1e-12);
+//!
+//! // `ħ = c = 1`: lengths are inverse energies
+//! let length: Value<f64, m> = Value::new(1.0);
+//! let length: Value<f64, unit!(ElectronVolt ** { -1 })> = length.convert_system::<Natural, _>();
+//! assert!((length.value / 5.067_730_7e6 - 1.0).abs() < 1e-6);
+//! ```
+//!
+//! Ill-defined conversions cannot compile:
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::base::{A, s};
+//! # use ucsi::units::system::gaussian::{Gaussian, Statcoulomb};
+//! # use ucsi::{unit, Value};
+//! let charge: Value<f64, Statcoulomb> = Value::new(1.0);
+//! let charge: Value<f64, unit!(A * s)> = charge.convert_system::<Gaussian, _>();
+//! ```
+
+use crate::{
+    units::any::{SiAnyUnit, SiOpsUnit},
+    Value,
+};
+
+pub mod cgs;
+pub mod gaussian;
+pub mod natural;
+
+/// A unit system other than SI.
+pub trait UnitSystem {
+    const NAME: &'static str;
+}
+
+/// Conversion from `T` to `Self` through the unit system `S`.
+pub trait SystemCastFrom<S: UnitSystem, T: SiOpsUnit>: SiOpsUnit {
+    /// `1 T = FACTOR Self`, including the [scale factors](SiOpsUnit::SCALE) of both units.
+    ///
+    /// The evaluation panics if the conversion is not well-defined.
+    const FACTOR: f64;
+}
+
+/// `T::SCALE / B::SCALE`, the factor between two scaled units of the same coherent units.
+pub(crate) const fn scale_ratio<T: SiOpsUnit, B: SiOpsUnit>() -> f64 {
+    T::SCALE.div(B::SCALE).to_f64()
+}
+
+/// `base ** exp` for const evaluation.
+pub(crate) const fn powi(mut base: f64, exp: i32) -> f64 {
+    let mut res = 1.0;
+    let mut n = exp.unsigned_abs();
+    // exponentiation by squaring
+    while n > 0 {
+        if n & 1 == 1 {
+            res *= base;
+        }
+        n >>= 1;
+        base *= base;
+    }
+    if exp < 0 {
+        1.0 / res
+    } else {
+        res
+    }
+}
+
+impl<T: SiAnyUnit + SiOpsUnit> Value<f64, T> {
+    /// Convert the value to a unit of another unit system, or SI.
+    ///
+    /// See [the `system` module](crate::units::system) for more information.
+    #[inline]
+    pub fn convert_system<S: UnitSystem, B: SiAnyUnit + SystemCastFrom<S, T>>(self) -> Value<f64, B> {
+        Value::new(self.value * B::FACTOR)
+    }
+
+    /// Convert the value to a unit of another unit system, or SI.
+    ///
+    /// See [the `system` module](crate::units::system) for more information.
+    #[inline]
+    pub const fn convert_system_const<S: UnitSystem, B: SiAnyUnit + SystemCastFrom<S, T>>(
+        self,
+    ) -> Value<f64, B> {
+        Value::new(self.value * B::FACTOR)
+    }
+}
+
+/// Define units of a unit system.
+///
+/// Works like `si_exported_unit_def`, but the units are never `Canonicalize`.
+macro_rules! __impl_system_unit_def {
+    (
+        alias $vis:vis mod $mod:ident;
+        $(
+            $(#[$attr:meta])*
+            unit $name:ident based on $ty:ty {
+                $($key:ident: $val:expr),*
+                $(,)?
+            } $(alias $($al:ident),+ $(,)?)?;
+        )+
+    ) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $(#[$attr])*
+            pub struct $name;
+
+            impl $crate::core::units::any::SiDefinedUnit for $name {
+                const DEF: $crate::core::units::any::SiDefinedUnitDefinition =
+                    $crate::core::units::any::SiDefinedUnitDefinition {
+                        $($key: $val),*
+                    };
+            }
+
+            impl $crate::core::units::any::SiOpsUnit for $name {
+                const UNIT_MAP: $crate::core::units::base::BaseUnitMap =
+                    <$ty as $crate::core::units::any::SiOpsUnit>::UNIT_MAP;
+                const SCALE: $crate::core::units::scale::Scale =
+                    <$ty as $crate::core::units::any::SiOpsUnit>::SCALE;
+            }
+
+            impl $crate::core::units::any::SiAnyUnit for $name {}
+        )+
+
+        #[allow(non_camel_case_types)]
+        $vis mod $mod {
+            $($(
+                $(pub type $al = super::$name;)+
+            )?)+
+        }
+    };
+}
+
+pub(crate) use __impl_system_unit_def as system_unit_def;
@@ -0,0 +1,101 @@
+//! # Natural units
+//!
+//! Natural units with `ħ = c = 1`, which are common in particle physics.
+//! [`ElectronVolt`] is the only base unit, and every mechanical quantity is a power of it:
+//! masses are energies, lengths and times are inverse energies.
+//!
+//! A SI unit built from `kg`, `m` and `s` converts to `eV ** {n}`,
+//! where `n` is the exponent of `kg` minus the exponents of `m` and `s`.
+//! The factor uses the exact values of `c`, `e` and `ħ` from the [`constants`](crate::constants) module.
+
+use cfg_if::cfg_if;
+
+use crate::{
+    constants,
+    fraction::Fraction,
+    macros::unit_def::si_base_unit_def,
+    units::{
+        any::SiOpsUnit,
+        base::BaseUnitMap,
+    },
+};
+
+use super::{powi, scale_ratio, SystemCastFrom, UnitSystem};
+
+/// Natural units, `ħ = c = 1`.
+pub struct Natural;
+
+impl UnitSystem for Natural {
+    const NAME: &'static str = "Natural";
+}
+
+si_base_unit_def! {
+    alias pub(crate) mod impl_aliases;
+
+    /// The base unit of natural units.
+    unit ElectronVolt {
+        full_name: "Electronvolt",
+        short_name: "electronvolt",
+        unit_symbol: "eV",
+    } alias eV;
+}
+
+/// Speed of light in vacuum, in `m / s`.
+const SPEED_OF_LIGHT: f64 = constants::C.value;
+/// Elementary charge, in `C`, also `J / eV`.
+const ELEMENTARY_CHARGE: f64 = constants::E.value;
+/// Reduced Planck constant, in `J * s`.
+const REDUCED_PLANCK: f64 = constants::HBAR.value;
+
+/// Map `map` to a power of electronvolt.
+///
+/// Returns the exponent and the factor, `1 map = factor eV ** {exponent}`.
+const fn to_natural(map: BaseUnitMap) -> (Fraction, f64) {
+    let ev = map.extra.get_unit::<ElectronVolt>();
+
+    let mut rest = map;
+    rest.meter = Fraction::ZERO;
+    rest.kilogram = Fraction::ZERO;
+    rest.second = Fraction::ZERO;
+    rest.extra = rest
+        .extra
+        .add(<ElectronVolt as SiOpsUnit>::UNIT_MAP.extra.fmul(ev.neg()));
+    if !rest.eq(&BaseUnitMap::EMPTY) {
+        panic!("cannot convert to natural units, only `kg`, `m` and `s` are supported")
+    }
+
+    let (kg, m, s) = (map.kilogram.simplify(), map.meter.simplify(), map.second.simplify());
+    if kg.denominator().get() != 1 || m.denominator().get() != 1 || s.denominator().get() != 1 {
+        panic!("cannot convert fractional powers to natural units")
+    }
+
+    // 1 kg = c^2 / e eV, 1 m = e / (ħ c) eV^-1, 1 s = e / ħ eV^-1
+    let factor = powi(SPEED_OF_LIGHT * SPEED_OF_LIGHT / ELEMENTARY_CHARGE, kg.numerator())
+        * powi(ELEMENTARY_CHARGE / (REDUCED_PLANCK * SPEED_OF_LIGHT), m.numerator())
+        * powi(ELEMENTARY_CHARGE / REDUCED_PLANCK, s.numerator());
+
+    (ev.add(kg).add(m.neg()).add(s.neg()), factor)
+}
+
+const fn natural_factor(from: (Fraction, f64), to: (Fraction, f64)) -> f64 {
+    if !from.0.eq(&to.0) {
+        panic!("cannot convert between unit systems")
+    }
+    from.1 / to.1
+}
+
+impl<T: SiOpsUnit, B: SiOpsUnit> SystemCastFrom<Natural, T> for B {
+    const FACTOR: f64 =
+        natural_factor(to_natural(T::UNIT_MAP), to_natural(B::UNIT_MAP)) * scale_ratio::<T, B>();
+}
+
+cfg_if! {
+    if #[cfg(feature = "alias_hidden")] {
+    } else if #[cfg(feature = "alias_mod")] {
+        pub mod aliases {
+            pub use super::impl_aliases::*;
+        }
+    } else if #[cfg(feature = "alias_export")] {
+        pub use impl_aliases::*;
+    }
+}
@@ -87,17 +87,51 @@
 //! Since rust's const evaluation and generic system is still under rapid development,
 //! this could be improved in the future. \
 //! Anyway, at least we can check units at compile time.
+//!
+//! ## Structurally different units
+//!
+//! `ops::Add` and `ops::Sub` require exactly the same unit type on both sides.
+//! If the two units are only equal under [`is_same_type`][crate::core::units::any::is_same_type],
+//! use the `cast_xxx` method family instead. The right-hand side is checked
+//! with [`CastFrom`] at compile time and the result has the left-hand unit.
+//!
+//! ```rust
+//! use ucsi::units::base::{kg, m, s};
+//! use ucsi::units::exported::force::Newton;
+//! use ucsi::unit;
+//! use ucsi::Value;
+//!
+//! let a: Value<f64, Newton> = Value::new(1.0);
+//! let b: Value<f64, unit!((kg * m) / (s ** { 2 }))> = Value::new(2.0);
+//! let sum: Value<f64, Newton> = a.cast_add(b);
+//! assert!(a.cast_partial_cmp(&b).unwrap().is_lt());
+//! ```
+//!
+//! ```rust,compile_fail
+//! # use ucsi::units::base::{kg, m, s};
+//! # use ucsi::units::exported::force::Newton;
+//! # use ucsi::Value;
+//! let a: Value<f64, Newton> = Value::new(1.0);
+//! let b: Value<f64, kg> = Value::new(2.0);
+//! let sum = a.cast_add(b);
+//! ```
 
-use core::{fmt, marker::PhantomData, ops};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops,
+};
 
 use crate::{
     __dbg_assert,
-    units::any::CastFrom,
+    units::any::{CastFrom, ReinterpretFrom},
 };
 
 use super::{
     ops::{Div, Mul, PowFrac, PowI},
-    units::any::{is_same_type, SiAnyUnit, SiOpsUnit},
+    units::any::{can_cast_kind, is_same_scale, is_same_type, SiAnyUnit, SiOpsUnit},
 };
 
 pub struct Value<V, T: SiAnyUnit> {
@@ -126,6 +160,43 @@ impl<T: SiAnyUnit, V: Clone> Clone for Value<V, T> {
 
 impl<T: SiAnyUnit, V: Copy> Copy for Value<V, T> {}
 
+impl<T: SiAnyUnit, V: Default> Default for Value<V, T> {
+    #[inline]
+    fn default() -> Self {
+        Value::new(V::default())
+    }
+}
+
+impl<T: SiAnyUnit, V: PartialEq<L>, L> PartialEq<Value<L, T>> for Value<V, T> {
+    #[inline]
+    fn eq(&self, other: &Value<L, T>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: SiAnyUnit, V: Eq> Eq for Value<V, T> {}
+
+impl<T: SiAnyUnit, V: PartialOrd<L>, L> PartialOrd<Value<L, T>> for Value<V, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Value<L, T>) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: SiAnyUnit, V: Ord> Ord for Value<V, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: SiAnyUnit, V: Hash> Hash for Value<V, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
 impl<T: SiAnyUnit, V> Value<V, T> {
     #[inline]
     pub const fn new(value: V) -> Value<V, T> {
@@ -153,6 +224,14 @@ impl<T: SiAnyUnit + SiOpsUnit, V> Value<V, T> {
         Value::new(self.value)
     }
 
+    /// Cast the value to a unit of the same dimension but another
+    /// [kind](crate::units::kind) of quantity, e.g. from torque to energy.
+    #[inline]
+    pub fn reinterpret_kind<B: SiAnyUnit + SiOpsUnit + ReinterpretFrom<T>>(self) -> Value<V, B> {
+        __dbg_assert!(B::CAN_REINTERPRET_FROM);
+        Value::new(self.value)
+    }
+
     /// # Safety
     ///
     /// This function is not rust-unsafe and will not create any ub.
@@ -183,6 +262,16 @@ impl<T: SiAnyUnit + SiOpsUnit, V: Copy> Value<V, T> {
         Value::new(self.value)
     }
 
+    /// Cast the value to a unit of the same dimension but another
+    /// [kind](crate::units::kind) of quantity, e.g. from torque to energy.
+    #[inline]
+    pub const fn reinterpret_kind_const<B: SiAnyUnit + SiOpsUnit + ReinterpretFrom<T>>(
+        self,
+    ) -> Value<V, B> {
+        __dbg_assert!(B::CAN_REINTERPRET_FROM);
+        Value::new(self.value)
+    }
+
     #[inline]
     pub const fn powi_type_const<const N: i32>(self) -> Value<V, PowI<T, N>> {
         Value::new(self.value)
@@ -203,7 +292,166 @@ impl<T: SiAnyUnit + SiOpsUnit, V: PartialEq> Value<V, T> {
 
     #[inline]
     pub fn is_equal<R: SiAnyUnit + SiOpsUnit>(&self, rhs: &Value<V, R>) -> bool {
-        is_same_type::<T, R>() && self.is_value_equal(rhs)
+        is_same_type::<T, R>() && is_same_scale::<T, R>() && self.is_value_equal(rhs)
+    }
+}
+
+/// Whether two values can be compared, i.e. whether `U` can be [cast](CastFrom) from `T`.
+const fn is_comparable<T: SiOpsUnit, U: SiOpsUnit>() -> bool {
+    is_same_type::<T, U>() && can_cast_kind::<T, U>() && is_same_scale::<T, U>()
+}
+
+impl<T: SiAnyUnit + SiOpsUnit, V: PartialOrd> Value<V, T> {
+    /// Compare two values whose units are the same type under [`is_same_type`],
+    /// of compatible [kinds](crate::units::kind) and with the same [scale factor](SiOpsUnit::SCALE).
+    ///
+    /// Returns `None` if the units differ or the values are not comparable.
+    /// See [`cast_partial_cmp`](Value::cast_partial_cmp) to reject different units at compile time.
+    #[inline]
+    pub fn partial_cmp_unit<R: SiAnyUnit + SiOpsUnit>(&self, rhs: &Value<V, R>) -> Option<Ordering> {
+        if is_comparable::<T, R>() {
+            self.value.partial_cmp(&rhs.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: SiAnyUnit + SiOpsUnit, V: Ord> Value<V, T> {
+    /// Compare two values whose units are the same type under [`is_same_type`],
+    /// of compatible [kinds](crate::units::kind) and with the same [scale factor](SiOpsUnit::SCALE).
+    ///
+    /// Returns `None` if the units differ.
+    /// See [`cast_cmp`](Value::cast_cmp) to reject different units at compile time.
+    #[inline]
+    pub fn cmp_unit<R: SiAnyUnit + SiOpsUnit>(&self, rhs: &Value<V, R>) -> Option<Ordering> {
+        if is_comparable::<T, R>() {
+            Some(self.value.cmp(&rhs.value))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: SiAnyUnit + SiOpsUnit, V> Value<V, T> {
+    /// Add a value whose unit can be cast from the left-hand unit.
+    ///
+    /// The result has the left-hand unit.
+    #[inline]
+    pub fn cast_add<L, B: SiAnyUnit + SiOpsUnit + CastFrom<T>>(
+        self,
+        rhs: Value<L, B>,
+    ) -> Value<V::Output, T>
+    where
+        V: ops::Add<L>,
+    {
+        __dbg_assert!(B::CAN_CAST_FROM);
+        Value::new(self.value.add(rhs.value))
+    }
+
+    /// Subtract a value whose unit can be cast from the left-hand unit.
+    ///
+    /// The result has the left-hand unit.
+    #[inline]
+    pub fn cast_sub<L, B: SiAnyUnit + SiOpsUnit + CastFrom<T>>(
+        self,
+        rhs: Value<L, B>,
+    ) -> Value<V::Output, T>
+    where
+        V: ops::Sub<L>,
+    {
+        __dbg_assert!(B::CAN_CAST_FROM);
+        Value::new(self.value.sub(rhs.value))
+    }
+
+    /// Check if a value whose unit can be cast from the left-hand unit is equal.
+    #[inline]
+    pub fn cast_eq<L, B: SiAnyUnit + SiOpsUnit + CastFrom<T>>(&self, rhs: &Value<L, B>) -> bool
+    where
+        V: PartialEq<L>,
+    {
+        __dbg_assert!(B::CAN_CAST_FROM);
+        self.value == rhs.value
+    }
+
+    /// Compare with a value whose unit can be cast from the left-hand unit.
+    ///
+    /// Same as [`partial_cmp_unit`](Value::partial_cmp_unit), but different units
+    /// do not compile instead of returning `None`.
+    /// Returns `None` only if the values are not comparable (e.g. `NaN`).
+    #[inline]
+    pub fn cast_partial_cmp<L, B: SiAnyUnit + SiOpsUnit + CastFrom<T>>(
+        &self,
+        rhs: &Value<L, B>,
+    ) -> Option<Ordering>
+    where
+        V: PartialOrd<L>,
+    {
+        __dbg_assert!(B::CAN_CAST_FROM);
+        self.value.partial_cmp(&rhs.value)
+    }
+
+    /// Compare with a value whose unit can be cast from the left-hand unit.
+    ///
+    /// Same as [`cmp_unit`](Value::cmp_unit), but different units
+    /// do not compile instead of returning `None`.
+    #[inline]
+    pub fn cast_cmp<B: SiAnyUnit + SiOpsUnit + CastFrom<T>>(&self, rhs: &Value<V, B>) -> Ordering
+    where
+        V: Ord,
+    {
+        __dbg_assert!(B::CAN_CAST_FROM);
+        self.value.cmp(&rhs.value)
+    }
+}
+
+impl<T: SiAnyUnit, V: PartialOrd> Value<V, T> {
+    /// Returns the smaller one of the two values.
+    ///
+    /// Same as [`Ord::min`] and [`f64::min`]: `self` is returned if the values are equal,
+    /// and if one of the values is not comparable with itself (e.g. `NaN`), the other one is returned.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        match self.value.partial_cmp(&other.value) {
+            Some(Ordering::Greater) => other,
+            Some(_) => self,
+            None if self.value.partial_cmp(&self.value).is_none() => other,
+            None => self,
+        }
+    }
+
+    /// Returns the larger one of the two values.
+    ///
+    /// Same as [`Ord::max`] and [`f64::max`]: `other` is returned if the values are equal,
+    /// and if one of the values is not comparable with itself (e.g. `NaN`), the other one is returned.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        match self.value.partial_cmp(&other.value) {
+            Some(Ordering::Greater) => self,
+            Some(_) => other,
+            None if self.value.partial_cmp(&self.value).is_none() => other,
+            None => self,
+        }
+    }
+
+    /// Restrict the value to the interval `[min, max]`.
+    ///
+    /// Same as [`Ord::clamp`] and [`f64::clamp`]: a value which is not comparable
+    /// with the bounds (e.g. `NaN`) is returned unchanged.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `min > max`, or if the bounds are not comparable (e.g. `NaN`).
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.value <= max.value, "`min` should not be greater than `max`");
+        if self.value < min.value {
+            min
+        } else if self.value > max.value {
+            max
+        } else {
+            self
+        }
     }
 }
 
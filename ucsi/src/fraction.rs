@@ -19,15 +19,39 @@ use core::{fmt, num::NonZeroU32};
 
 use crate::macros::unwrap::unwrap_option_const;
 
+/// The stored denominator.
+///
+/// The `const_dim` feature needs [`Fraction`] as a const generic parameter,
+/// which cannot contain a `NonZeroU32`, so the denominator is a plain `u32` there.
+/// It is guaranteed to be non-zero by every constructor.
+#[cfg(not(feature = "const_dim"))]
+type Denominator = NonZeroU32;
+#[cfg(feature = "const_dim")]
+type Denominator = u32;
+
+#[cfg(not(feature = "const_dim"))]
+#[inline]
+const fn denominator(r: NonZeroU32) -> Denominator {
+    r
+}
+
+#[cfg(feature = "const_dim")]
+#[inline]
+const fn denominator(r: NonZeroU32) -> Denominator {
+    r.get()
+}
+
+/// Constant fraction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Fraction(i32, NonZeroU32);
+#[cfg_attr(feature = "const_dim", derive(core::marker::ConstParamTy))]
+pub struct Fraction(i32, Denominator);
 
 impl fmt::Display for Fraction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.1.get() == 1 {
+        if self.denominator().get() == 1 {
             write!(f, "{}", self.0)
         } else {
-            write!(f, "{}/{}", self.0, self.1)
+            write!(f, "{}/{}", self.0, self.denominator())
         }
     }
 }
@@ -40,14 +64,14 @@ impl Fraction {
     #[inline]
     pub const fn new(l: i32, r: u32) -> Fraction {
         assert!(r != 0, "denominator should never be zero");
-        Fraction(l, unwrap_option_const!(NonZeroU32::new(r)))
+        Fraction(l, denominator(unwrap_option_const!(NonZeroU32::new(r))))
     }
 
     #[inline]
     pub const fn eq(&self, rhs: &Self) -> bool {
         let lhs = self.simplify();
         let rhs = rhs.simplify();
-        lhs.0 == rhs.0 && lhs.1.get() == rhs.1.get()
+        lhs.0 == rhs.0 && lhs.denominator().get() == rhs.denominator().get()
     }
 
     #[inline]
@@ -57,20 +81,29 @@ impl Fraction {
 
     #[inline]
     pub const fn denominator(&self) -> NonZeroU32 {
-        self.1
+        #[cfg(not(feature = "const_dim"))]
+        {
+            self.1
+        }
+        #[cfg(feature = "const_dim")]
+        {
+            unwrap_option_const!(NonZeroU32::new(self.1))
+        }
     }
 
     #[inline]
     pub const fn simplify(self) -> Self {
         if let Some(left) = NonZeroU32::new(self.0.unsigned_abs()) {
-            let g = gcd::binary_nonzero_u32(left, self.1);
+            let g = gcd::binary_nonzero_u32(left, self.denominator());
             let i32g = g.get() as i32;
             Fraction(
                 self.0 / i32g,
-                unwrap_option_const!(NonZeroU32::new(self.1.get() / g.get())),
+                denominator(unwrap_option_const!(NonZeroU32::new(
+                    self.denominator().get() / g.get()
+                ))),
             )
         } else {
-            Fraction(0, unwrap_option_const!(NonZeroU32::new(1)))
+            Fraction::ZERO
         }
     }
 
@@ -86,8 +119,8 @@ impl Fraction {
         } else if rhs.is_zero() {
             self
         } else {
-            let Fraction(na, da) = self;
-            let Fraction(nb, db) = rhs;
+            let (na, da) = (self.0, self.denominator());
+            let (nb, db) = (rhs.0, rhs.denominator());
             let g = gcd::binary_nonzero_u32(
                 unwrap_option_const!(NonZeroU32::new(na.unsigned_abs())),
                 da,
@@ -95,7 +128,7 @@ impl Fraction {
             if g.get() == 1 {
                 Fraction(
                     na * db.get() as i32 + da.get() as i32 * nb,
-                    unwrap_option_const!(da.checked_mul(db)),
+                    denominator(unwrap_option_const!(da.checked_mul(db))),
                 )
             } else {
                 let s = da.get() / g.get();
@@ -105,11 +138,13 @@ impl Fraction {
                     g,
                 );
                 if g2.get() == 1 {
-                    Fraction(t, unwrap_option_const!(NonZeroU32::new(s * db.get())))
+                    Fraction(t, denominator(unwrap_option_const!(NonZeroU32::new(s * db.get()))))
                 } else {
                     Fraction(
                         t / g2.get() as i32,
-                        unwrap_option_const!(NonZeroU32::new(s * (db.get() / g2.get()))),
+                        denominator(unwrap_option_const!(NonZeroU32::new(
+                            s * (db.get() / g2.get())
+                        ))),
                     )
                 }
             }
@@ -117,19 +152,33 @@ impl Fraction {
         .simplify()
     }
 
+    /// Add, `None` if the numerator or the denominator overflows.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (da, db) = (self.denominator().get() as i128, rhs.denominator().get() as i128);
+        let num = self.0 as i128 * db + rhs.0 as i128 * da;
+        let den = da * db;
+        let g = gcd::binary_u128(num.unsigned_abs(), den as u128) as i128;
+        let (num, den) = (num / g, den / g);
+        if num < i32::MIN as i128 || num > i32::MAX as i128 || den > u32::MAX as i128 {
+            None
+        } else {
+            Some(Fraction::new(num as i32, den as u32))
+        }
+    }
+
     #[inline]
     pub const fn as_reciprocal(self) -> Self {
         if self.0 == 0 {
             Fraction::ZERO
         } else if self.0 > 0 {
             Fraction(
-                self.1.get() as i32,
-                unwrap_option_const!(NonZeroU32::new(self.0.unsigned_abs())),
+                self.denominator().get() as i32,
+                denominator(unwrap_option_const!(NonZeroU32::new(self.0.unsigned_abs()))),
             )
         } else {
             Fraction(
-                -(self.1.get() as i32),
-                unwrap_option_const!(NonZeroU32::new(self.0.unsigned_abs())),
+                -(self.denominator().get() as i32),
+                denominator(unwrap_option_const!(NonZeroU32::new(self.0.unsigned_abs()))),
             )
         }
     }
@@ -148,6 +197,19 @@ impl Fraction {
         }
     }
 
+    /// Multiply by an integer, `None` if the numerator overflows.
+    #[inline]
+    pub const fn checked_imul(self, rhs: i32) -> Option<Self> {
+        if rhs == 0 || self.is_zero() {
+            Some(Self::ZERO)
+        } else {
+            match self.0.checked_mul(rhs) {
+                Some(num) => Some(Fraction(num, self.1).simplify()),
+                None => None,
+            }
+        }
+    }
+
     #[inline]
     pub const fn fmul(self, rhs: Self) -> Self {
         let lhs = self.simplify();
@@ -156,10 +218,26 @@ impl Fraction {
         let rhs2 = Fraction(rhs.0, lhs.1).simplify();
         Fraction(
             lhs2.0 * rhs2.0,
-            unwrap_option_const!(lhs2.1.checked_mul(rhs2.1)),
+            denominator(unwrap_option_const!(lhs2.denominator().checked_mul(rhs2.denominator()))),
         )
     }
 
+    /// Multiply, `None` if the numerator or the denominator overflows.
+    #[inline]
+    pub const fn checked_fmul(self, rhs: Self) -> Option<Self> {
+        let lhs = self.simplify();
+        let rhs = rhs.simplify();
+        let lhs2 = Fraction(lhs.0, rhs.1).simplify();
+        let rhs2 = Fraction(rhs.0, lhs.1).simplify();
+        match (
+            lhs2.0.checked_mul(rhs2.0),
+            lhs2.denominator().checked_mul(rhs2.denominator()),
+        ) {
+            (Some(num), Some(den)) => Some(Fraction(num, denominator(den))),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn format_plain(&self, w: &mut impl fmt::Write) -> fmt::Result {
         if self.denominator().get() == 1 && self.numerator() >= 0 {
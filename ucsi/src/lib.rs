@@ -126,10 +126,71 @@
 //! crate's constant float mathematical operations,
 //! and the lib's standard associated units offer constant
 //! conversion method for those types.
+//!
+//! The physical constants are also available as `SoftF64` values
+//! in the `soft` module of each constants module.
+//!
+//! #### `canonical`
+//!
+//! Disabled by default, included in the `full` feature.
+//!
+//! This feature depends on the [`typenum`](https://docs.rs/crate/typenum) crate
+//! and enables the [`units::canonical`](crate::units::canonical) module,
+//! which normalizes units with the same dimension into a single rust type.
+//!
+//! #### `type_dimension`
+//!
+//! Disabled by default, included in the `full` feature.
+//!
+//! This feature implies `canonical` and enables the [`units::dimension`](crate::units::dimension) module,
+//! which checks dimensions with plain trait bounds (`where U: SameDimension<V>`)
+//! instead of const evaluation.
+//!
+//! #### `proc_macros`
+//!
+//! Disabled by default, included in the `full` feature.
+//!
+//! This feature depends on the `ucsi-macros` crate and enables the procedural
+//! [`ops::unit`](crate::ops::unit) macro, which supports operator precedence,
+//! `^` powers and string input, e.g. `unit!(kg * m / s^2)` or `unit!("kg·m/s²")`.
+//!
+//! #### `catalogue`
+//!
+//! Disabled by default, not included in the `full` feature.
+//!
+//! This feature implies `use_std`, depends on the [`toml`](https://docs.rs/crate/toml) crate
+//! and enables the [`catalogue`](crate::catalogue) module,
+//! which generates associated units from catalogue files in build scripts.
+//!
+//! ### Unit semantics
+//!
+//! #### `angle`
+//!
+//! Disabled by default, not included in the `full` feature.
+//!
+//! SI treats plane angles as dimensionless.
+//! This feature adds radian as an extra base unit,
+//! so angles cannot be mixed up with plain numbers. See the [`units::angle`](crate::units::angle) module.
+//!
+//! ### Nightly features
+//!
+//! #### `const_dim`
+//!
+//! **Experimental.** Requires the `nightly` feature and a nightly compiler.
+//! Not included in the `nightly_full` feature.
+//!
+//! This feature enables the [`core::dim_value`](crate::core::dim_value) module,
+//! which stores the dimension as a const generic `BaseUnitMap`
+//! with the help of `adt_const_params` and `generic_const_exprs`.
 
 #![allow(clippy::module_inception)]
 
 #![cfg_attr(not(feature = "use_std"), no_std)]
+#![cfg_attr(feature = "const_dim", allow(incomplete_features))]
+#![cfg_attr(
+    feature = "const_dim",
+    feature(adt_const_params, generic_const_exprs, unsized_const_params)
+)]
 
 /// Core module of the library.
 pub mod core;
@@ -141,6 +202,9 @@ pub mod fraction;
 #[cfg(not(feature = "fraction"))]
 mod fraction;
 
+#[cfg(feature = "catalogue")]
+pub mod catalogue;
+
 #[cfg(feature = "internal_macros")]
 /// Internally used macros.
 /// Not designed to be soundly used in external codebase.
@@ -153,7 +217,7 @@ use cfg_if::cfg_if;
 // re-export
 pub use crate::core::units::any::{SiAnyUnit, SiDefinedUnit};
 pub use crate::core::value::Value;
-pub use crate::core::{ops, units};
+pub use crate::core::{constants, ops, units};
 
 cfg_if! {
     if #[cfg(feature = "const_soft_float")] {
@@ -167,6 +231,9 @@ mod feature_error {
     #[cfg(all(not(feature = "nightly"), feature = "infer_cast"))]
     compile_error!("`infer_cast` feature requires rust's nightly feature");
 
+    #[cfg(all(not(feature = "nightly"), feature = "const_dim"))]
+    compile_error!("`const_dim` feature requires rust's nightly feature");
+
     #[cfg(
         all(
             any(
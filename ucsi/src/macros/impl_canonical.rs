@@ -0,0 +1,17 @@
+#[cfg(feature = "canonical")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_canonicalize {
+    ($name:ty => $base:ty) => {
+        impl $crate::core::units::canonical::Canonicalize for $name {
+            type Canonical = $crate::core::units::canonical::Canonical<$base>;
+        }
+    };
+}
+
+#[cfg(not(feature = "canonical"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_canonicalize {
+    ($name:ty => $base:ty) => {};
+}
@@ -64,10 +64,7 @@ macro_rules! __impl_unit_conversion {
                     <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit
                 >
             )
-                -> $crate::core::value::Value<
-                    $vtype,
-                    <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit
-                >
+                -> $crate::core::value::Value<$vtype, $unit>
             {
                 let $idtf = val.value;
                 let value = $blockf;
@@ -101,10 +98,7 @@ macro_rules! __impl_unit_conversion {
                     <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit
                 >
             )
-                -> $crate::core::value::Value<
-                    $vtype,
-                    <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit
-                >
+                -> $crate::core::value::Value<$vtype, $unit>
             {
                 let $idtf = val.value;
                 let value = $blockf;
@@ -113,3 +107,57 @@ macro_rules! __impl_unit_conversion {
         }
     };
 }
+
+/// **Note:** Use the alias `ucsi::macros::conversion::exact_scale`.
+/// 
+/// Implement [`ScaledUnit`](crate::core::units::scale::ScaledUnit) for associated units,
+/// `1 unit = num / den base unit`.
+/// 
+/// ## Syntax
+/// 
+/// ```rust,ignore
+/// exact_scale! {
+///     Type: num / den;
+///     ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! __impl_exact_scale {
+    ($($unit:ty: $num:literal / $den:literal;)+) => {
+        $(
+            impl $crate::core::units::scale::ScaledUnit for $unit {
+                type Base = <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit;
+                const FACTOR: $crate::core::units::scale::Scale =
+                    <Self::Base as $crate::core::units::any::SiOpsUnit>::SCALE
+                        .mul($crate::core::units::scale::Scale::new($num, $den));
+            }
+        )+
+    };
+}
+
+/// **Note:** Use the alias `ucsi::macros::conversion::linear_unit`.
+/// 
+/// Implement [`LinearUnit`](crate::core::units::scale::LinearUnit) for associated units,
+/// `1 unit = factor base unit`, where `factor` is an `f64` expression.
+/// 
+/// ## Syntax
+/// 
+/// ```rust,ignore
+/// linear_unit! {
+///     Type: factor;
+///     ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! __impl_linear_unit {
+    ($($unit:ty: $factor:expr;)+) => {
+        $(
+            impl $crate::core::units::scale::LinearUnit for $unit {
+                type Base = <$unit as $crate::core::units::associated::SiAssociatedUnit>::BaseUnit;
+                const FACTOR: f64 = <Self::Base as $crate::core::units::any::SiOpsUnit>::SCALE
+                    .to_f64()
+                    * $factor;
+            }
+        )+
+    };
+}
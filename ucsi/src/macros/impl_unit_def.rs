@@ -66,12 +66,12 @@ macro_rules! __impl_si_associated_unit_def {
             $($crate::__impl_unit_conversion! { $name $block_tt; })?
         )+
         
-        $($(
-            $vis mod $mod {
-                #[allow(non_camel_case_types)]
+        #[allow(non_camel_case_types)]
+        $vis mod $mod {
+            $($(
                 $(pub type $al = super::$name;)+
-            }
-        )?)+
+            )?)+
+        }
     };
 }
 
@@ -87,7 +87,12 @@ macro_rules! __impl_si_associated_unit_def {
 ///     unit UnitName
 ///     based on BaseUnitType {
 ///         // unit static configuration
-///         // see `ucsi::core::units::associated::SiAssociatedUnitDefinition`.
+///         // see `ucsi::core::units::exported::SiExportedUnitDefinition`.
+///         full_name: "...",
+///         short_name: "...",
+///         unit_symbol: "...",
+///         // optional, see `ucsi::core::units::kind`.
+///         kind: Some(...),
 ///     }
 ///     alias alias1, alias2, ... ?;
 /// 
@@ -101,7 +106,10 @@ macro_rules! __impl_si_exported_unit_def {
         $(
             $(#[$attr:meta])*
             unit $name:ident based on $ty:ty {
-                $($key:ident: $val:expr),*
+                full_name: $full_name:expr,
+                short_name: $short_name:expr,
+                unit_symbol: $unit_symbol:expr
+                $(, kind: $kind:expr)?
                 $(,)?
             } $(alias $($al:ident),+ $(,)?)?;
         )+
@@ -115,7 +123,10 @@ macro_rules! __impl_si_exported_unit_def {
                 type BaseUnit = $ty;
                 const DEF: $crate::core::units::exported::SiExportedUnitDefinition =
                     $crate::core::units::exported::SiExportedUnitDefinition {
-                        $($key: $val),*
+                        full_name: $full_name,
+                        short_name: $short_name,
+                        unit_symbol: $unit_symbol,
+                        kind: $crate::__impl_si_exported_unit_def!(@kind $($kind)?),
                     };
             }
 
@@ -131,16 +142,103 @@ macro_rules! __impl_si_exported_unit_def {
             impl $crate::core::units::any::SiOpsUnit for $name {
                 const UNIT_MAP: $crate::core::units::base::BaseUnitMap =
                     <$name as $crate::core::units::exported::SiExportedUnit>::BaseUnit::UNIT_MAP;
+                const KIND: ::core::option::Option<$crate::core::units::any::QuantityKind> =
+                    <$name as $crate::core::units::exported::SiExportedUnit>::DEF.kind;
+                const SCALE: $crate::core::units::scale::Scale =
+                    <$name as $crate::core::units::exported::SiExportedUnit>::BaseUnit::SCALE;
             }
 
+            $crate::__impl_canonicalize!($name => $ty);
+
             impl $crate::core::units::any::SiAnyUnit for $name {}
         )+
         
-        $($(
-            $vis mod $mod {
-                #[allow(non_camel_case_types)]
+        #[allow(non_camel_case_types)]
+        $vis mod $mod {
+            $($(
                 $(pub type $al = super::$name;)+
+            )?)+
+        }
+    };
+    (@kind) => {
+        ::core::option::Option::None
+    };
+    (@kind $kind:expr) => {
+        $kind
+    };
+}
+/// **Note:** Use the alias `ucsi::macros::unit_def::si_base_unit_def`.
+/// 
+/// Every unit declares a new base dimension identified by the path of the unit type,
+/// see `ucsi::core::units::extra`.
+/// 
+/// ## Syntax
+/// 
+/// ```rust,ignore
+/// si_base_unit_def! {
+///     alias visibility? mod alias_mod_name;
+/// 
+///     /// add docs here...
+///     unit UnitName {
+///         // unit static configuration
+///         // see `ucsi::core::units::base::SiBaseUnitDefinition`.
+///     } alias alias1, alias2, ... ?;
+/// 
+///     ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! __impl_si_base_unit_def {
+    (
+        alias $vis:vis mod $mod:ident;
+        $(
+            $(#[$attr:meta])*
+            unit $name:ident {
+                $($key:ident: $val:expr),*
+                $(,)?
+            } $(alias $($al:ident),+ $(,)?)?;
+        )+
+    ) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $(#[$attr])*
+            pub struct $name;
+
+            impl $crate::core::units::base::SiBaseUnit for $name {
+                const DEF: $crate::core::units::base::SiBaseUnitDefinition =
+                    $crate::core::units::base::SiBaseUnitDefinition {
+                        $($key: $val),*
+                    };
+            }
+
+            impl $crate::core::units::any::SiDefinedUnit for $name {
+                const DEF: $crate::core::units::any::SiDefinedUnitDefinition =
+                    $crate::core::units::any::SiDefinedUnitDefinition {
+                        full_name: <$name as $crate::core::units::base::SiBaseUnit>::DEF.full_name,
+                        short_name: <$name as $crate::core::units::base::SiBaseUnit>::DEF.short_name,
+                        unit_symbol: <$name as $crate::core::units::base::SiBaseUnit>::DEF.unit_symbol,
+                    };
+            }
+
+            impl $crate::core::units::any::SiOpsUnit for $name {
+                const UNIT_MAP: $crate::core::units::base::BaseUnitMap =
+                    $crate::core::units::base::BaseUnitMap {
+                        extra: $crate::core::units::extra::ExtraDimensions::base(
+                            ::core::concat!(::core::module_path!(), "::", ::core::stringify!($name)),
+                            <$name as $crate::core::units::base::SiBaseUnit>::DEF.unit_symbol,
+                        ),
+                        ..$crate::core::units::base::BaseUnitMap::EMPTY
+                    };
             }
-        )?)+
+
+            impl $crate::core::units::any::SiAnyUnit for $name {}
+        )+
+
+        #[allow(non_camel_case_types)]
+        $vis mod $mod {
+            $($(
+                $(pub type $al = super::$name;)+
+            )?)+
+        }
     };
-}
\ No newline at end of file
+}
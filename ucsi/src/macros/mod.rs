@@ -15,6 +15,7 @@
 
 #![allow(unused_imports)]
 
+mod impl_canonical;
 mod impl_cast;
 mod impl_const_unwrap;
 mod impl_conversion;
@@ -46,11 +47,17 @@ mod util_def {
         pub use crate::__impl_si_associated_unit_def as si_associated_unit_def;
         /// Generate exported type definition.
         pub use crate::__impl_si_exported_unit_def as si_exported_unit_def;
+        /// Generate base type definition with a new base dimension.
+        pub use crate::__impl_si_base_unit_def as si_base_unit_def;
     }
 
     pub mod conversion {
         /// Generate associated type conversion definition.
         pub use crate::__impl_unit_conversion as unit_conversion;
+        /// Generate the exact factor of associated types.
+        pub use crate::__impl_exact_scale as exact_scale;
+        /// Generate the floating-point factor of associated types.
+        pub use crate::__impl_linear_unit as linear_unit;
     }
 }
 